@@ -0,0 +1,52 @@
+#![allow(missing_docs)]
+use iced::{
+    Element, Task,
+    widget::{button, center, column, text},
+};
+
+#[derive(Default)]
+struct State {
+    action_text: &'static str,
+}
+
+#[derive(Debug, Clone)]
+enum Message {
+    Delete,
+    Confirmed(bool),
+}
+
+fn main() -> iced::Result {
+    iced::run(State::update, State::view)
+}
+
+impl State {
+    fn update(&mut self, message: Message) -> Task<Message> {
+        match message {
+            Message::Delete => {
+                iced_dialog::confirm("Delete", "Delete all your work?")
+                    .map(Message::Confirmed)
+            }
+            Message::Confirmed(confirmed) => {
+                self.action_text = if confirmed {
+                    "User deleted their work"
+                } else {
+                    "User kept their work"
+                };
+
+                Task::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Element<'_, Message> {
+        let base = center(
+            column![
+                text(self.action_text),
+                button("Delete").on_press(Message::Delete)
+            ]
+            .spacing(14.0),
+        );
+
+        iced_dialog::host(base).into()
+    }
+}
@@ -51,17 +51,19 @@ impl State {
 
         dialog(self.is_open, base, dialog_content)
             .title("Save")
-            .push_button(iced_dialog::button("Save work", Message::Saved))
+            .push_default_button("Save work", Message::Saved)
             .push_button(iced_dialog::button("Cancel", Message::Cancelled))
             .width(350)
             .height(234)
             .on_press(Message::Cancelled)
+            .on_escape(Message::Cancelled)
             .into()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use iced::keyboard;
     use iced_test::{Error, simulator};
 
     use super::*;
@@ -111,4 +113,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn dialog_saves_on_enter() {
+        let mut save = State {
+            action_text: "",
+            is_open: true,
+        };
+        let mut ui = simulator(save.view());
+
+        let _ = ui.tap_key(keyboard::key::Named::Enter);
+        for message in ui.into_messages() {
+            let _ = save.update(message);
+        }
+
+        assert!(!save.is_open);
+        assert_eq!(save.action_text, "User saved their work");
+    }
 }
@@ -1,12 +1,49 @@
 //! Dialogs can be used to provide users with
 //! important information and make them act on it.
+//!
+//! Besides the [`Dialog`] widget, this module provides prebuilt dialogs for
+//! common tasks, like an [`Alert`], a [`Confirm`] or a [`Prompt`]. Each of
+//! them can be turned into a [`Dialog`] to configure it further.
+pub mod alert;
+pub mod choice;
+pub mod confirm;
+pub mod error;
+mod focus;
+pub mod host;
+pub mod progress;
+pub mod prompt;
+pub mod queue;
+pub mod wizard;
+
+use std::any::Any;
+use std::rc::Rc;
+
 use iced_widget::{
-    Container, Row, Theme, column, container, mouse_area, opaque, stack, text,
+    Theme, button, checkbox, container, text,
     text::{Fragment, IntoFragment},
-    vertical_space,
 };
 
-use crate::core::{self, Color, Element, Length, Padding, Pixels, alignment};
+use crate::core::{
+    self, Background, Border, Clipboard, Color, Element, Event, Layout, Length,
+    Padding, Pixels, Point, Rectangle, Shadow, Shell, Size, Transformation,
+    Vector, alignment, keyboard, layout, mouse, overlay, renderer,
+    theme::{self, palette},
+    time::{Duration, Instant},
+    touch,
+    widget::{Id, Operation, Tree, Widget, tree},
+    window,
+};
+
+pub use crate::core::animation::Easing;
+pub use alert::{Alert, Severity};
+pub use choice::Choice;
+pub use confirm::Confirm;
+pub use error::Error;
+pub use host::Host;
+pub use progress::Progress;
+pub use prompt::Prompt;
+pub use queue::Queue;
+pub use wizard::Wizard;
 
 /// A message dialog.
 ///
@@ -17,12 +54,20 @@ use crate::core::{self, Color, Element, Length, Padding, Pixels, alignment};
 /// & [`max_height`] (or [`width`] & [`height`] when set to a fixed pixel value) are used. If
 /// these aren't set, [`DEFAULT_MAX_WIDTH`] and/or [`DEFAULT_MAX_HEIGHT`] are used.
 ///
+/// The base, the content and the buttons keep their widget state while the [`Dialog`] is opened
+/// and closed, so things like scroll positions and text cursors survive toggling it.
+///
+/// Dialogs can be stacked by placing a [`Dialog`] that [covers the window] in the content of
+/// another one. Each open dialog adds its own backdrop on top of the ones below it, and only the
+/// topmost one receives input.
+///
 /// [`buttons`]: Dialog::with_buttons
 /// [`title`]: Dialog::title
 /// [`max_width`]: Dialog::max_width
 /// [`max_height`]: Dialog::max_height
 /// [`width`]: Dialog::width
 /// [`height`]: Dialog::height
+/// [covers the window]: Dialog::cover_window
 pub struct Dialog<
     'a,
     Message,
@@ -33,25 +78,45 @@ pub struct Dialog<
     Theme: 'a + Catalog,
 {
     is_open: bool,
+    id: Option<Id>,
     base: Element<'a, Message, Theme, Renderer>,
     title: Option<Fragment<'a>>,
+    severity: Option<Severity>,
     content: Element<'a, Message, Theme, Renderer>,
     buttons: Vec<Element<'a, Message, Theme, Renderer>>,
+    has_checkbox: bool,
+    is_checked: bool,
+    checked_buttons: Vec<CheckedButton<'a, Message>>,
     on_press: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_escape: Option<Box<dyn Fn() -> Message + 'a>>,
+    on_enter: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    on_close: Option<Box<dyn Fn() -> Message + 'a>>,
+    is_closable: bool,
+    is_draggable: bool,
+    is_resizable: bool,
+    modality: Modality,
     font: Option<Renderer::Font>,
     width: Length,
     height: Length,
     max_width: Option<f32>,
     max_height: Option<f32>,
+    min_width: Option<f32>,
+    min_height: Option<f32>,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
     spacing: f32,
     padding_inner: Padding,
     padding_outer: Padding,
     button_alignment: alignment::Vertical,
+    cover_window: bool,
+    transition: Option<Transition>,
+    duration: Duration,
+    easing: Easing,
+    reduced_motion: bool,
     class: <Theme as Catalog>::Class<'a>,
     title_class: <Theme as text::Catalog>::Class<'a>,
     container_class: <Theme as container::Catalog>::Class<'a>,
+    close_class: <Theme as button::Catalog>::Class<'a>,
 }
 
 impl<'a, Message, Theme, Renderer> Dialog<'a, Message, Theme, Renderer>
@@ -81,34 +146,70 @@ where
 
         Self {
             is_open,
+            id: None,
             base: base.into(),
             title: None,
+            severity: None,
             content,
             buttons,
+            has_checkbox: false,
+            is_checked: false,
+            checked_buttons: Vec::new(),
             on_press: None,
+            on_escape: None,
+            on_enter: None,
+            on_close: None,
+            is_closable: true,
+            is_draggable: false,
+            is_resizable: false,
+            modality: Modality::Modal,
             font: None,
             width: size.width.fluid(),
             height: size.height.fluid(),
             max_width: None,
             max_height: None,
+            min_width: None,
+            min_height: None,
             horizontal_alignment: alignment::Horizontal::Center,
             vertical_alignment: alignment::Vertical::Center,
             spacing: 8.0,
             padding_inner: 24.into(),
             padding_outer: Padding::ZERO,
             button_alignment: alignment::Vertical::Top,
+            cover_window: false,
+            transition: None,
+            duration: Duration::from_millis(200),
+            easing: Easing::EaseOutCubic,
+            reduced_motion: false,
             class: <Theme as Catalog>::default(),
             title_class: <Theme as Catalog>::default_title(),
             container_class: <Theme as Catalog>::default_container(),
+            close_class: <Theme as Catalog>::default_close_button(),
         }
     }
 
+    /// Sets the [`Id`] of the [`Dialog`].
+    ///
+    /// Widget operations see the open [`Dialog`] as a container with this
+    /// [`Id`] and its bounds. Changing the [`Id`] of an open, modal [`Dialog`]
+    /// moves the focus to its first focusable widget, like opening it does.
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the [`Dialog`]'s title.
     pub fn title(mut self, title: impl IntoFragment<'a>) -> Self {
         self.title = Some(title.into_fragment());
         self
     }
 
+    /// Sets the [`Severity`] shown by an icon next to the [`Dialog`]'s title.
+    pub(crate) fn severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
     /// Sets the message that will be produced when the [`Dialog`]'s backdrop is pressed.
     pub fn on_press(mut self, on_press: Message) -> Self
     where
@@ -141,6 +242,138 @@ where
         self
     }
 
+    /// Sets the message that will be produced when the Escape key is pressed
+    /// while the [`Dialog`] is open.
+    ///
+    /// When it is not set, the Escape key produces the [close message] of a
+    /// [closable] [`Dialog`].
+    ///
+    /// [close message]: Dialog::on_close
+    /// [closable]: Dialog::closable
+    pub fn on_escape(mut self, on_escape: Message) -> Self
+    where
+        Message: Clone,
+    {
+        self.on_escape = Some(Box::new(move || on_escape.clone()));
+        self
+    }
+
+    /// Sets the message that will be produced when the Escape key is pressed
+    /// while the [`Dialog`] is open.
+    ///
+    /// This is analogous to [`Dialog::on_escape`], but using a closure to produce
+    /// the message.
+    pub fn on_escape_with(
+        mut self,
+        on_escape: impl Fn() -> Message + 'a,
+    ) -> Self {
+        self.on_escape = Some(Box::new(on_escape));
+        self
+    }
+
+    /// Sets the message that will be produced when the Escape key is pressed
+    /// while the [`Dialog`] is open, if `Some`.
+    pub fn on_escape_maybe(mut self, on_escape: Option<Message>) -> Self
+    where
+        Message: Clone,
+    {
+        self.on_escape =
+            on_escape.map(|message| Box::new(move || message.clone()) as _);
+
+        self
+    }
+
+    /// Sets the message that will be produced when the close button in the
+    /// top-right corner of the [`Dialog`] is pressed.
+    ///
+    /// The close button is only shown when this is set, and the [`Dialog`]
+    /// is [closable].
+    ///
+    /// The close button cannot be focused: the Escape key is the way to
+    /// close the [`Dialog`] from the keyboard, and produces this message
+    /// unless an [Escape message] is set.
+    ///
+    /// [closable]: Dialog::closable
+    /// [Escape message]: Dialog::on_escape
+    pub fn on_close(mut self, on_close: Message) -> Self
+    where
+        Message: Clone,
+    {
+        self.on_close = Some(Box::new(move || on_close.clone()));
+        self
+    }
+
+    /// Sets the message that will be produced when the close button in the
+    /// top-right corner of the [`Dialog`] is pressed.
+    ///
+    /// This is analogous to [`Dialog::on_close`], but using a closure to produce
+    /// the message.
+    pub fn on_close_with(
+        mut self,
+        on_close: impl Fn() -> Message + 'a,
+    ) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Sets the message that will be produced when the close button in the
+    /// top-right corner of the [`Dialog`] is pressed, if `Some`.
+    pub fn on_close_maybe(mut self, on_close: Option<Message>) -> Self
+    where
+        Message: Clone,
+    {
+        self.on_close =
+            on_close.map(|message| Box::new(move || message.clone()) as _);
+
+        self
+    }
+
+    /// Sets whether the close button of the [`Dialog`] is shown.
+    ///
+    /// It is `true` by default, but the close button is only shown when a
+    /// [close message] is set.
+    ///
+    /// [close message]: Dialog::on_close
+    pub fn closable(mut self, is_closable: bool) -> Self {
+        self.is_closable = is_closable;
+        self
+    }
+
+    /// Sets whether the user can move the [`Dialog`] by dragging its title
+    /// area.
+    ///
+    /// The [`Dialog`] is kept within the area left by its [outer padding],
+    /// and stays where it was moved to when it is closed and opened again.
+    ///
+    /// [outer padding]: Dialog::padding_outer
+    pub fn draggable(mut self, is_draggable: bool) -> Self {
+        self.is_draggable = is_draggable;
+        self
+    }
+
+    /// Sets whether the user can resize the [`Dialog`] by dragging its edges
+    /// and corners.
+    ///
+    /// The [`Dialog`] is kept within its [minimum] and [maximum] sizes, but
+    /// may grow past [`DEFAULT_MAX_WIDTH`] and [`DEFAULT_MAX_HEIGHT`]. Like
+    /// its position, the size chosen by the user is kept when the [`Dialog`]
+    /// is closed and opened again.
+    ///
+    /// [minimum]: Dialog::min_width
+    /// [maximum]: Dialog::max_width
+    pub fn resizable(mut self, is_resizable: bool) -> Self {
+        self.is_resizable = is_resizable;
+        self
+    }
+
+    /// Sets the [`Modality`] of the [`Dialog`].
+    ///
+    /// It is [`Modality::Modal`] by default.
+    pub fn modality(mut self, modality: Modality) -> Self {
+        self.modality = modality;
+        self
+    }
+
     /// Sets the [`Dialog`]'s width.
     pub fn width(mut self, width: impl Into<Length>) -> Self {
         self.width = width.into();
@@ -165,6 +398,18 @@ where
         self
     }
 
+    /// Sets the [`Dialog`]'s minimum width.
+    pub fn min_width(mut self, min_width: impl Into<Pixels>) -> Self {
+        self.min_width = Some(min_width.into().0);
+        self
+    }
+
+    /// Sets the [`Dialog`]'s minimum height.
+    pub fn min_height(mut self, min_height: impl Into<Pixels>) -> Self {
+        self.min_height = Some(min_height.into().0);
+        self
+    }
+
     /// Aligns the [`Dialog`] to the left.
     pub fn align_left(self) -> Self {
         self.align_x(alignment::Horizontal::Left)
@@ -234,6 +479,46 @@ where
         self
     }
 
+    /// Sets whether the [`Dialog`] covers the whole window instead of only
+    /// its base.
+    ///
+    /// When `true`, the backdrop and the dialog are drawn as an overlay, so
+    /// the [`Dialog`] can be placed anywhere in the widget tree, including
+    /// the content of another [`Dialog`].
+    pub fn cover_window(mut self, cover_window: bool) -> Self {
+        self.cover_window = cover_window;
+        self
+    }
+
+    /// Sets the [`Transition`] played when the [`Dialog`] opens and closes.
+    ///
+    /// [`Dialog`]s are not animated by default.
+    pub fn transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
+
+    /// Sets the duration of the [`Dialog`]'s [`Transition`].
+    pub fn duration(mut self, duration: impl Into<Duration>) -> Self {
+        self.duration = duration.into();
+        self
+    }
+
+    /// Sets the [`Easing`] of the [`Dialog`]'s [`Transition`].
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets whether the user prefers reduced motion.
+    ///
+    /// When `true`, the [`Dialog`]'s [`Transition`] is not played and it opens
+    /// and closes instantly.
+    pub fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+
     /// Sets the [`Font`] of the [`Dialog`]'s title.
     ///
     /// [`Font`]: https://docs.iced.rs/iced_core/text/trait.Renderer.html#associatedtype.Font
@@ -251,6 +536,116 @@ where
         self
     }
 
+    /// Adds the given button to the [`Dialog`] as its default button.
+    ///
+    /// Pressing Enter while the [`Dialog`] is open produces the given message,
+    /// which should be the one the button produces when pressed. The button
+    /// keeps its own style. If more than one default button is added, the
+    /// last one takes precedence.
+    pub fn push_default(
+        mut self,
+        button: impl Into<Element<'a, Message, Theme, Renderer>>,
+        message: Message,
+    ) -> Self {
+        self.on_enter = Some(Box::new(move |_| message.clone()));
+        self.push_button(button)
+    }
+
+    /// Adds the default button to the [`Dialog`].
+    ///
+    /// The default button is rendered with an accent style, and pressing Enter
+    /// while the [`Dialog`] is open produces its message. If more than one
+    /// default button is added, the last one takes precedence.
+    ///
+    /// See [`push_default`] to make a button of your own the default one.
+    ///
+    /// [`push_default`]: Self::push_default
+    pub fn push_default_button(
+        self,
+        content: &'a str,
+        message: Message,
+    ) -> Self {
+        self.push_default_checked_button(content, move |_| message.clone())
+    }
+
+    /// Adds a button to the [`Dialog`] whose message is built from whether
+    /// its [`checkbox`] is checked when the button is pressed.
+    ///
+    /// [`checkbox`]: Self::checkbox
+    pub fn push_checked_button(
+        self,
+        content: &'a str,
+        on_press: impl Fn(bool) -> Message + 'a,
+    ) -> Self {
+        self.push_checked(content, Rc::new(on_press), false)
+    }
+
+    /// Adds the default button to the [`Dialog`], with its message built
+    /// from whether its [`checkbox`] is checked.
+    ///
+    /// See [`push_default_button`] and [`push_checked_button`].
+    ///
+    /// [`checkbox`]: Self::checkbox
+    /// [`push_default_button`]: Self::push_default_button
+    /// [`push_checked_button`]: Self::push_checked_button
+    pub fn push_default_checked_button(
+        mut self,
+        content: &'a str,
+        on_press: impl Fn(bool) -> Message + 'a,
+    ) -> Self {
+        let on_press: Rc<dyn Fn(bool) -> Message + 'a> = Rc::new(on_press);
+
+        self.on_enter = Some(Box::new({
+            let on_press = on_press.clone();
+
+            move |is_checked| on_press(is_checked)
+        }));
+
+        self.push_checked(content, on_press, true)
+    }
+
+    /// Adds a [`CheckedButton`] to the [`Dialog`], after the buttons added
+    /// so far.
+    fn push_checked(
+        mut self,
+        content: &'a str,
+        on_press: Rc<dyn Fn(bool) -> Message + 'a>,
+        is_default: bool,
+    ) -> Self {
+        self.checked_buttons.push(CheckedButton {
+            index: self.buttons.len() + self.checked_buttons.len(),
+            content,
+            on_press,
+            is_default,
+        });
+
+        self
+    }
+
+    /// Builds the [`CheckedButton`]s of the [`Dialog`] with whether its
+    /// [`checkbox`] is checked, and puts them among its other buttons.
+    ///
+    /// [`checkbox`]: Self::checkbox
+    fn build_checked_buttons(&mut self) {
+        for button in std::mem::take(&mut self.checked_buttons) {
+            let pressed = crate::button(
+                button.content,
+                (button.on_press)(self.is_checked),
+            );
+
+            self.buttons.insert(
+                button.index,
+                if button.is_default {
+                    pressed
+                        .class(<Theme as Catalog>::default_accent_button())
+                        .into()
+                } else {
+                    pressed.into()
+                },
+            );
+        }
+    }
+
     /// Adds a button to the [`Dialog`], if `Some`.
     pub fn push_button_maybe(
         self,
@@ -271,6 +666,47 @@ where
         buttons.into_iter().fold(self, Self::push_button)
     }
 
+    /// Adds a checkbox to the footer of the [`Dialog`], like a "Don't ask
+    /// again" option.
+    ///
+    /// The checkbox sits on the left of the footer, with the buttons on its
+    /// right. `on_toggle` is called with whether it is now checked, and the
+    /// buttons added with [`push_checked_button`] report it with their
+    /// message. If more than one checkbox is added, the last one takes
+    /// precedence.
+    ///
+    /// [`push_checked_button`]: Self::push_checked_button
+    pub fn checkbox(
+        mut self,
+        label: impl IntoFragment<'a>,
+        is_checked: bool,
+        on_toggle: impl Fn(bool) -> Message + 'a,
+    ) -> Self
+    where
+        Theme: checkbox::Catalog,
+    {
+        let checkbox = iced_widget::checkbox(is_checked)
+            .label(label)
+            .on_toggle(on_toggle)
+            .text_size(14)
+            .into();
+
+        self.is_checked = is_checked;
+
+        if self.has_checkbox {
+            self.buttons[0] = checkbox;
+        } else {
+            self.buttons.insert(0, checkbox);
+            self.has_checkbox = true;
+
+            for button in &mut self.checked_buttons {
+                button.index += 1;
+            }
+        }
+
+        self
+    }
+
     /// Sets the backdrop color of the [`Dialog`].
     pub fn backdrop(self, color: impl Into<Color>) -> Self
     where
@@ -278,12 +714,15 @@ where
     {
         let backdrop_color = color.into();
 
-        self.style(move |_theme| Style { backdrop_color })
+        self.style(move |theme, status| Style {
+            backdrop_color,
+            ..Catalog::style(theme, &<Theme as Catalog>::default(), status)
+        })
     }
 
     /// Sets the style of the [`Dialog`].
     #[must_use]
-    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
     where
         <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
     {
@@ -319,6 +758,20 @@ where
         self
     }
 
+    /// Sets the style of the [`Dialog`]'s close button.
+    #[must_use]
+    pub fn close_style(
+        mut self,
+        style: impl Fn(&Theme, button::Status) -> button::Style + 'a,
+    ) -> Self
+    where
+        <Theme as button::Catalog>::Class<'a>: From<button::StyleFn<'a, Theme>>,
+    {
+        self.close_class =
+            (Box::new(style) as button::StyleFn<'a, Theme>).into();
+        self
+    }
+
     /// Sets the style class of the [`Dialog`].
     #[must_use]
     pub fn class(
@@ -349,102 +802,14 @@ where
         self
     }
 
-    fn view(self) -> Element<'a, Message, Theme, Renderer>
-    where
-        <Theme as container::Catalog>::Class<'a>:
-            From<container::StyleFn<'a, Theme>>,
-    {
-        let dialog = self.is_open.then(|| {
-            let has_title = self.title.is_some();
-            let has_buttons = !self.buttons.is_empty();
-
-            let contents = Container::new(column![
-                self.title.map(|title| {
-                    let text = text(title)
-                        .size(20)
-                        .line_height(text::LineHeight::Absolute(Pixels(26.0)))
-                        .class(self.title_class);
-
-                    if let Some(font) = self.font {
-                        text.font(font)
-                    } else {
-                        text
-                    }
-                }),
-                has_title.then_some(vertical_space().height(12)),
-                self.content
-            ])
-            .padding(self.padding_inner);
-
-            let contents = if has_buttons {
-                contents.width(Length::Fill)
-            } else {
-                contents
-            };
-
-            let buttons = has_buttons.then_some(
-                Container::new(
-                    Row::with_children(self.buttons)
-                        .spacing(self.spacing)
-                        .align_y(self.button_alignment),
-                )
-                .height(80)
-                .padding(self.padding_inner),
-            );
-
-            let max_width = self.max_width.unwrap_or(
-                if has_buttons && !matches!(self.width, Length::Fixed(_)) {
-                    DEFAULT_MAX_WIDTH
-                } else {
-                    f32::INFINITY
-                },
-            );
-
-            let max_height = self.max_height.unwrap_or(
-                if has_buttons && !matches!(self.height, Length::Fixed(_)) {
-                    DEFAULT_MAX_HEIGHT
-                } else {
-                    f32::INFINITY
-                },
-            );
-
-            let content = Container::new(column![
-                contents,
-                has_buttons.then_some(vertical_space()),
-                buttons,
-            ])
-            .width(self.width)
-            .height(self.height)
-            .max_width(max_width)
-            .max_height(max_height)
-            .class(self.container_class)
-            .clip(true);
-
-            let backdrop = mouse_area(
-                container(opaque(content))
-                    .style(move |theme| container::Style {
-                        background: Some(
-                            Catalog::style(theme, &self.class)
-                                .backdrop_color
-                                .into(),
-                        ),
-                        ..Default::default()
-                    })
-                    .padding(self.padding_outer)
-                    .width(Length::Fill)
-                    .height(Length::Fill)
-                    .align_x(self.horizontal_alignment)
-                    .align_y(self.vertical_alignment),
-            );
-
-            if let Some(on_press) = self.on_press {
-                opaque(backdrop.on_press(on_press()))
-            } else {
-                opaque(backdrop)
-            }
-        });
-
-        stack![self.base, dialog].into()
+    /// Sets the style class of the [`Dialog`]'s close button.
+    #[must_use]
+    pub fn close_class(
+        mut self,
+        class: impl Into<<Theme as button::Catalog>::Class<'a>>,
+    ) -> Self {
+        self.close_class = class.into();
+        self
     }
 }
 
@@ -458,71 +823,1966 @@ pub const DEFAULT_MAX_WIDTH: f32 = 400.0;
 /// Check the main documentation of [`Dialog`] to see when this is used.
 pub const DEFAULT_MAX_HEIGHT: f32 = 260.0;
 
-impl<'a, Message, Theme, Renderer> From<Dialog<'a, Message, Theme, Renderer>>
-    for Element<'a, Message, Theme, Renderer>
+const TITLE_SIZE: f32 = 20.0;
+const TITLE_LINE_HEIGHT: f32 = 26.0;
+const TITLE_SPACING: f32 = 12.0;
+const FOOTER_HEIGHT: f32 = 80.0;
+const ICON_SIZE: f32 = TITLE_LINE_HEIGHT;
+const ICON_SPACING: f32 = 10.0;
+const CLOSE_SIZE: f32 = TITLE_LINE_HEIGHT;
+
+/// Whether a [`Dialog`] keeps the user from interacting with its base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Modality {
+    /// The backdrop covers the base and keeps it from receiving input.
+    ///
+    /// Opening the dialog moves the focus into it, and closing it gives the
+    /// focus back to the base.
+    #[default]
+    Modal,
+    /// There is no backdrop, and the base keeps receiving input outside of
+    /// the dialog, like for a find and replace panel.
+    ///
+    /// Opening the dialog leaves the focus where it is, and the keyboard is
+    /// left to the base unless a widget of the dialog has the focus.
+    Modeless,
+    /// Like [`Modality::Modeless`], but the backdrop is still drawn and
+    /// lets input through to the base.
+    ClickThrough,
+}
+
+/// The transition played when a [`Dialog`] opens and closes.
+///
+/// The backdrop always fades in and out, while the dialog itself either
+/// scales or slides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transition {
+    /// The dialog scales up from its center.
+    Scale,
+    /// The dialog slides in from the edge it is aligned to.
+    ///
+    /// Centered dialogs slide in from the bottom.
+    Slide,
+}
+
+/// The internal state of a [`Dialog`].
+#[derive(Debug)]
+struct State<P: core::text::Paragraph> {
+    focus: focus::Trap,
+    /// The [`Id`] of the [`Dialog`] the focus was last moved into.
+    id: Option<Id>,
+    title: core::widget::text::State<P>,
+    /// Whether the close button is being pressed.
+    is_close_pressed: bool,
+    /// How far the user moved the [`Dialog`] from its aligned position.
+    offset: Vector,
+    /// Where the [`Dialog`] is grabbed while it is being dragged, relative to
+    /// its offset.
+    grab: Option<Point>,
+    /// The size the user resized the [`Dialog`] to.
+    size: Option<Size>,
+    /// The resize in progress, if any.
+    resize: Option<Resize>,
+    /// Whether another modal [`Dialog`] is open in the content.
+    is_covered: bool,
+    animation: core::Animation<bool>,
+    now: Instant,
+}
+
+impl<P: core::text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self {
+            focus: focus::Trap::default(),
+            id: None,
+            title: core::widget::text::State::default(),
+            is_close_pressed: false,
+            offset: Vector::ZERO,
+            grab: None,
+            size: None,
+            resize: None,
+            is_covered: false,
+            animation: core::Animation::new(false),
+            now: Instant::now(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Dialog<'a, Message, Theme, Renderer>
 where
     Renderer: 'a + core::Renderer + core::text::Renderer,
     Theme: 'a + Catalog,
-    Message: 'a + Clone,
-    <Theme as container::Catalog>::Class<'a>:
-        From<container::StyleFn<'a, Theme>>,
 {
-    fn from(dialog: Dialog<'a, Message, Theme, Renderer>) -> Self {
-        dialog.view()
+    /// Returns whether the [`Dialog`] plays a [`Transition`].
+    fn is_animated(&self) -> bool {
+        self.transition.is_some() && !self.reduced_motion
     }
-}
 
-/// The style of a [`Dialog`].
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Style {
-    /// The [`Dialog`]'s backdrop.
-    pub backdrop_color: Color,
-}
+    /// Returns whether the [`Dialog`] keeps the user from interacting with
+    /// its base.
+    fn is_modal(&self) -> bool {
+        self.modality == Modality::Modal
+    }
 
-/// The theme catalog of a [`Dialog`].
-pub trait Catalog: text::Catalog + container::Catalog {
-    /// The item class of the [`Catalog`].
-    type Class<'a>;
+    /// Returns the [`mouse::Cursor`] of the base of the open [`Dialog`]
+    /// with the given layout.
+    ///
+    /// The base of a [modal] [`Dialog`] never sees the cursor, while others
+    /// only lose it under the dialog itself.
+    ///
+    /// [modal]: Modality::Modal
+    fn base_cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) -> mouse::Cursor {
+        let is_over_dialog = layout
+            .children()
+            .nth(1)
+            .is_some_and(|area| cursor.is_over(area.child(0).bounds()));
 
-    /// The default class produced by the [`Catalog`].
-    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+        if self.is_modal() || is_over_dialog {
+            mouse::Cursor::Unavailable
+        } else {
+            cursor
+        }
+    }
 
-    /// The default class for the [`Dialog`]'s title.
-    fn default_title<'a>() -> <Self as text::Catalog>::Class<'a> {
-        <Self as text::Catalog>::default()
+    /// Returns whether the [`Dialog`] shows its close button.
+    fn is_closable(&self) -> bool {
+        self.is_closable && self.on_close.is_some()
     }
 
-    /// The default class for the [`Dialog`]'s container.
-    fn default_container<'a>() -> <Self as container::Catalog>::Class<'a> {
-        <Self as container::Catalog>::default()
+    /// Returns whether the [`Dialog`] needs to be drawn, which is also the
+    /// case while its closing [`Transition`] is being played.
+    fn is_visible(&self, state: &State<Renderer::Paragraph>) -> bool {
+        self.is_open
+            || self.is_animated()
+                && (state.animation.value()
+                    || state.animation.is_animating(state.now))
     }
 
-    /// The [`Style`] of a class.
-    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
-}
+    /// Lays out the title, the content and the buttons of the open
+    /// [`Dialog`] within the given [`Size`].
+    fn layout_dialog(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        size: Size,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let has_buttons = !self.buttons.is_empty();
+        let min_width = self.min_width.unwrap_or(0.0);
+        let min_height = self.min_height.unwrap_or(0.0);
 
-/// A styling function for a [`Dialog`].
-pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+        let (width, height) = match state.size {
+            Some(size) if self.is_resizable => (
+                Length::Fixed(
+                    size.width
+                        .min(self.max_width.unwrap_or(f32::INFINITY))
+                        .max(min_width),
+                ),
+                Length::Fixed(
+                    size.height
+                        .min(self.max_height.unwrap_or(f32::INFINITY))
+                        .max(min_height),
+                ),
+            ),
+            _ => (self.width, self.height),
+        };
 
-impl Catalog for Theme {
-    type Class<'a> = StyleFn<'a, Self>;
+        let max_width = self.max_width.unwrap_or(
+            if has_buttons && !matches!(width, Length::Fixed(_)) {
+                DEFAULT_MAX_WIDTH
+            } else {
+                f32::INFINITY
+            },
+        );
 
-    fn default<'a>() -> <Self as Catalog>::Class<'a> {
-        Box::new(default)
-    }
+        let max_height = self.max_height.unwrap_or(
+            if has_buttons && !matches!(height, Length::Fixed(_)) {
+                DEFAULT_MAX_HEIGHT
+            } else {
+                f32::INFINITY
+            },
+        );
 
-    fn default_container<'a>() -> <Self as container::Catalog>::Class<'a> {
-        Box::new(|theme| container::background(theme.palette().background))
-    }
+        let limits = layout::Limits::new(Size::ZERO, size)
+            .shrink(self.padding_outer)
+            .min_width(min_width)
+            .min_height(min_height)
+            .width(width)
+            .height(height)
+            .max_width(max_width)
+            .max_height(max_height);
 
-    fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style {
-        class(self)
-    }
+        let footer_height = if has_buttons { FOOTER_HEIGHT } else { 0.0 };
+        let body_limits = limits
+            .loose()
+            .shrink(Size::new(0.0, footer_height))
+            .shrink(self.padding_inner);
+
+        let icon_width = if self.title.is_some() && self.severity.is_some() {
+            ICON_SIZE + ICON_SPACING
+        } else {
+            0.0
+        };
+
+        let (close_size, close_width) = if self.is_closable() {
+            (CLOSE_SIZE, CLOSE_SIZE + ICON_SPACING)
+        } else {
+            (0.0, 0.0)
+        };
+
+        let title = if let Some(title) = &self.title {
+            core::widget::text::layout(
+                &mut state.title,
+                renderer,
+                &body_limits.shrink(Size::new(icon_width + close_width, 0.0)),
+                title,
+                core::widget::text::Format {
+                    size: Some(Pixels(TITLE_SIZE)),
+                    line_height: text::LineHeight::Absolute(Pixels(
+                        TITLE_LINE_HEIGHT,
+                    )),
+                    font: self.font,
+                    ..core::widget::text::Format::default()
+                },
+            )
+        } else {
+            layout::Node::new(Size::ZERO)
+        };
+
+        let title_height = if self.title.is_some() || self.is_closable() {
+            title.size().height.max(close_size) + TITLE_SPACING
+        } else {
+            0.0
+        };
+
+        let content = self.content.as_widget_mut().layout(
+            &mut tree.children[1],
+            renderer,
+            &body_limits.shrink(Size::new(0.0, title_height)),
+        );
+
+        let body = Size::new(
+            (icon_width + title.size().width + close_width)
+                .max(content.size().width)
+                + self.padding_inner.x(),
+            title_height + content.size().height + self.padding_inner.y(),
+        );
+
+        let size = if has_buttons {
+            limits.resolve(width, height, limits.max())
+        } else {
+            limits.resolve(width, height, body)
+        };
+
+        let footer = if has_buttons {
+            self.layout_footer(tree, renderer, size.width)
+                .move_to((0.0, body.height.max(size.height - FOOTER_HEIGHT)))
+        } else {
+            layout::Node::new(Size::ZERO)
+        };
+
+        let padding =
+            Vector::new(self.padding_inner.left, self.padding_inner.top);
+
+        layout::Node::with_children(
+            size,
+            vec![
+                title.move_to(
+                    Point::ORIGIN + padding + Vector::new(icon_width, 0.0),
+                ),
+                content.move_to(
+                    Point::ORIGIN + padding + Vector::new(0.0, title_height),
+                ),
+                footer,
+                layout::Node::new(Size::new(close_size, close_size)).move_to((
+                    size.width - self.padding_inner.right - CLOSE_SIZE,
+                    self.padding_inner.top,
+                )),
+            ],
+        )
+    }
+
+    /// Lays out the footer of the open [`Dialog`] with the given width.
+    ///
+    /// The checkbox, if any, is placed on the left and centered on the
+    /// buttons, which fill the rest of the footer.
+    fn layout_footer(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        width: f32,
+    ) -> layout::Node {
+        let (checkbox, buttons) =
+            self.buttons.split_at_mut(usize::from(self.has_checkbox));
+        let (checkbox_tree, buttons_tree) =
+            tree.children[2..].split_at_mut(checkbox.len());
+
+        let checkbox = checkbox.first_mut().map(|checkbox| {
+            checkbox.as_widget_mut().layout(
+                &mut checkbox_tree[0],
+                renderer,
+                &layout::Limits::new(
+                    Size::ZERO,
+                    Size::new(width, FOOTER_HEIGHT),
+                )
+                .shrink(self.padding_inner),
+            )
+        });
+
+        let offset = checkbox
+            .as_ref()
+            .map_or(0.0, |checkbox| checkbox.size().width + self.spacing);
+
+        let buttons = layout::flex::resolve(
+            layout::flex::Axis::Horizontal,
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new(width - offset, FOOTER_HEIGHT),
+            ),
+            Length::Fill,
+            Length::Fixed(FOOTER_HEIGHT),
+            self.padding_inner,
+            self.spacing,
+            self.button_alignment.into(),
+            buttons,
+            buttons_tree,
+        );
+
+        let Some(checkbox) = checkbox else {
+            return buttons;
+        };
+
+        let row = buttons.children().first().map_or(
+            Rectangle::new(
+                Point::new(self.padding_inner.left, self.padding_inner.top),
+                checkbox.size(),
+            ),
+            layout::Node::bounds,
+        );
+
+        let height = checkbox.size().height;
+        let checkbox = checkbox.move_to((
+            self.padding_inner.left,
+            row.y + (row.height - height) / 2.0,
+        ));
+
+        // The buttons are placed against the right padding, whether they
+        // fill the rest of the footer or not.
+        let right = buttons
+            .children()
+            .last()
+            .map(layout::Node::bounds)
+            .map_or(self.padding_inner.left, |bounds| bounds.x + bounds.width);
+        let shift = (width - self.padding_inner.right - right).max(offset);
+
+        layout::Node::with_children(
+            Size::new(width, FOOTER_HEIGHT),
+            std::iter::once(checkbox)
+                .chain(buttons.children().iter().map(|button| {
+                    button.clone().translate(Vector::new(shift, 0.0))
+                }))
+                .collect(),
+        )
+    }
+
+    /// Lays out the area covered by the backdrop of the [`Dialog`], with the
+    /// aligned dialog as its only child.
+    fn layout_area(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        size: Size,
+    ) -> layout::Node {
+        let offset =
+            Vector::new(self.padding_outer.left, self.padding_outer.top);
+
+        let dialog = self
+            .layout_dialog(tree, renderer, size)
+            .align(
+                self.horizontal_alignment.into(),
+                self.vertical_alignment.into(),
+                Size::new(
+                    size.width - self.padding_outer.x(),
+                    size.height - self.padding_outer.y(),
+                ),
+            )
+            .translate(offset);
+
+        if !self.is_draggable && !self.is_resizable {
+            return layout::Node::with_children(size, vec![dialog]);
+        }
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let bounds = dialog.bounds();
+
+        // Keep the edges opposite to the resize handle in place
+        if let Some(resize) = state.resize {
+            let start = resize.start;
+            let x = match resize.handle.x {
+                Some(Edge::Start) => start.x + start.width - bounds.width,
+                _ => start.x,
+            };
+            let y = match resize.handle.y {
+                Some(Edge::Start) => start.y + start.height - bounds.height,
+                _ => start.y,
+            };
+
+            state.offset = Vector::new(x - bounds.x, y - bounds.y);
+        }
+        let min = Vector::new(
+            self.padding_outer.left - bounds.x,
+            self.padding_outer.top - bounds.y,
+        );
+        let max = Vector::new(
+            size.width - self.padding_outer.right - bounds.x - bounds.width,
+            size.height - self.padding_outer.bottom - bounds.y - bounds.height,
+        );
+
+        state.offset = Vector::new(
+            state.offset.x.min(max.x).max(min.x),
+            state.offset.y.min(max.y).max(min.y),
+        );
+
+        layout::Node::with_children(size, vec![dialog.translate(state.offset)])
+    }
+
+    /// Runs the [`Operation`] on the title, the content and the buttons of
+    /// the open [`Dialog`].
+    fn operate_dialog(
+        &mut self,
+        trees: &mut [Tree],
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        operation.container(self.id.as_ref(), layout.bounds());
+        operation.traverse(&mut |operation| {
+            if let Some(title) = &self.title {
+                operation.text(None, layout.child(0).bounds(), title);
+            }
+
+            for ((child, tree), layout) in std::iter::once(&mut self.content)
+                .chain(&mut self.buttons)
+                .zip(trees.iter_mut())
+                .zip(dialog_children(layout))
+            {
+                child
+                    .as_widget_mut()
+                    .operate(tree, layout, renderer, operation);
+            }
+        });
+    }
+
+    /// Returns the [`mouse::Interaction`] of the area covered by the
+    /// backdrop of the open [`Dialog`].
+    fn mouse_interaction_area(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let interaction = std::iter::once(&self.content)
+            .chain(&self.buttons)
+            .zip(dialog_children(layout.child(0)))
+            .zip(&tree.children[1..])
+            .map(|((child, layout), tree)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(tree, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default();
+
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        let handle = state.resize.map(|resize| resize.handle).or_else(|| {
+            cursor.position().filter(|_| self.is_resizable).and_then(
+                |position| Handle::at(layout.child(0).bounds(), position),
+            )
+        });
+
+        if state.grab.is_some() {
+            mouse::Interaction::Grabbing
+        } else if let Some(handle) = handle {
+            handle.interaction()
+        } else if self.is_closable()
+            && cursor.is_over(layout.child(0).child(3).bounds())
+        {
+            mouse::Interaction::Pointer
+        } else if self.is_draggable
+            && cursor.is_over(title_area(layout.child(0)))
+        {
+            mouse::Interaction::Grab
+        } else if interaction == mouse::Interaction::None
+            && cursor.is_over(if self.is_modal() {
+                layout.bounds()
+            } else {
+                layout.child(0).bounds()
+            })
+        {
+            mouse::Interaction::Idle
+        } else {
+            interaction
+        }
+    }
+
+    /// Draws the backdrop and the dialog in the area covered by the
+    /// backdrop of the [`Dialog`].
+    fn draw_area(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let bounds = layout.bounds();
+        let dialog_layout = layout.child(0);
+        let dialog_bounds = dialog_layout.bounds();
+
+        let progress = if self.is_animated() {
+            state.animation.interpolate(0.0, 1.0, state.now)
+        } else {
+            1.0
+        };
+
+        let status =
+            if self.is_animated() && state.animation.is_animating(state.now) {
+                if state.animation.value() {
+                    Status::Opening
+                } else {
+                    Status::Closing
+                }
+            } else if state.grab.is_some() || state.resize.is_some() {
+                Status::Dragging
+            } else if state.is_covered {
+                Status::Inactive
+            } else {
+                Status::Active
+            };
+
+        let dialog_style = Catalog::style(theme, &self.class, status);
+        let container_style =
+            <Theme as container::Catalog>::style(theme, &self.container_class);
+        let style = renderer::Style {
+            text_color: container_style
+                .text_color
+                .or(dialog_style.text_color)
+                .unwrap_or(style.text_color),
+        };
+        let title_style = text::Style {
+            color: <Theme as text::Catalog>::style(theme, &self.title_class)
+                .color
+                .or(dialog_style.title_color),
+        };
+
+        let transformation = match self.transition {
+            Some(Transition::Scale) if progress < 1.0 => {
+                let center = dialog_bounds.center();
+
+                Transformation::translate(center.x, center.y)
+                    * Transformation::scale(progress)
+                    * Transformation::translate(-center.x, -center.y)
+            }
+            Some(Transition::Slide) if progress < 1.0 => {
+                let distance = match (
+                    self.horizontal_alignment,
+                    self.vertical_alignment,
+                ) {
+                    (_, alignment::Vertical::Top) => Vector::new(
+                        0.0,
+                        bounds.y - dialog_bounds.y - dialog_bounds.height,
+                    ),
+                    (
+                        alignment::Horizontal::Left,
+                        alignment::Vertical::Center,
+                    ) => Vector::new(
+                        bounds.x - dialog_bounds.x - dialog_bounds.width,
+                        0.0,
+                    ),
+                    (
+                        alignment::Horizontal::Right,
+                        alignment::Vertical::Center,
+                    ) => Vector::new(
+                        bounds.x + bounds.width - dialog_bounds.x,
+                        0.0,
+                    ),
+                    _ => Vector::new(
+                        0.0,
+                        bounds.y + bounds.height - dialog_bounds.y,
+                    ),
+                };
+
+                let offset = distance * (1.0 - progress);
+
+                Transformation::translate(offset.x, offset.y)
+            }
+            _ => Transformation::IDENTITY,
+        };
+
+        renderer.with_layer(bounds, |renderer| {
+            if self.modality != Modality::Modeless {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        ..renderer::Quad::default()
+                    },
+                    dialog_style.backdrop_color.scale_alpha(progress),
+                );
+            }
+
+            renderer.with_transformation(transformation, |renderer| {
+                self.draw_surface(&dialog_style, renderer, dialog_layout);
+
+                container::draw_background(
+                    renderer,
+                    &container_style,
+                    dialog_bounds,
+                );
+
+                let Some(viewport) = bounds.intersection(&dialog_bounds) else {
+                    return;
+                };
+
+                renderer.with_layer(viewport, |renderer| {
+                    if self.title.is_some() {
+                        if let Some(severity) = self.severity {
+                            self.draw_icon(
+                                severity,
+                                renderer,
+                                theme,
+                                dialog_layout.child(0).bounds(),
+                                &viewport,
+                            );
+                        }
+
+                        core::widget::text::draw(
+                            renderer,
+                            &style,
+                            dialog_layout.child(0).bounds(),
+                            state.title.raw(),
+                            title_style,
+                            &viewport,
+                        );
+                    }
+
+                    if self.is_closable() {
+                        self.draw_close(
+                            state,
+                            renderer,
+                            theme,
+                            dialog_layout.child(3).bounds(),
+                            cursor,
+                            &viewport,
+                        );
+                    }
+
+                    for ((child, tree), layout) in
+                        std::iter::once(&self.content)
+                            .chain(&self.buttons)
+                            .zip(&tree.children[1..])
+                            .zip(dialog_children(dialog_layout))
+                    {
+                        child.as_widget().draw(
+                            tree, renderer, theme, &style, layout, cursor,
+                            &viewport,
+                        );
+                    }
+                });
+            });
+        });
+    }
+
+    /// Draws the background, the border, the shadow and the footer
+    /// background of the open [`Dialog`] with the given [`Style`].
+    fn draw_surface(
+        &self,
+        style: &Style,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+    ) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border: style.border,
+                shadow: style.shadow,
+                ..renderer::Quad::default()
+            },
+            style
+                .background
+                .unwrap_or(Background::Color(Color::TRANSPARENT)),
+        );
+
+        if let Some(background) = style.footer_background
+            && !self.buttons.is_empty()
+        {
+            let radius = style.border.radius;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.child(2).bounds().shrink(Padding {
+                        right: style.border.width,
+                        bottom: style.border.width,
+                        left: style.border.width,
+                        top: 0.0,
+                    }),
+                    border: core::border::rounded(
+                        core::border::Radius::default()
+                            .bottom_left(radius.bottom_left)
+                            .bottom_right(radius.bottom_right),
+                    ),
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+    }
+
+    /// Draws the icon of the given [`Severity`] next to the title of the
+    /// [`Dialog`].
+    fn draw_icon(
+        &self,
+        severity: Severity,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        title_bounds: Rectangle,
+        viewport: &Rectangle,
+    ) {
+        let bounds = Rectangle::new(
+            Point::new(
+                title_bounds.x - ICON_SIZE - ICON_SPACING,
+                title_bounds.y,
+            ),
+            Size::new(ICON_SIZE, ICON_SIZE),
+        );
+        let colors = Catalog::severity(theme, severity);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: core::border::rounded(ICON_SIZE / 2.0),
+                ..renderer::Quad::default()
+            },
+            colors.color,
+        );
+
+        renderer.fill_text(
+            core::Text {
+                content: severity.glyph().to_owned(),
+                bounds: bounds.size(),
+                size: Pixels(16.0),
+                line_height: text::LineHeight::default(),
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Center,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+            },
+            bounds.center(),
+            colors.text,
+            *viewport,
+        );
+    }
+
+    /// Draws the close button of the [`Dialog`] within the given bounds.
+    fn draw_close(
+        &self,
+        state: &State<Renderer::Paragraph>,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let status = if state.is_close_pressed {
+            button::Status::Pressed
+        } else if cursor.is_over(bounds) {
+            button::Status::Hovered
+        } else {
+            button::Status::Active
+        };
+        let style =
+            <Theme as button::Catalog>::style(theme, &self.close_class, status);
+
+        if let Some(background) = style.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: style.border,
+                    shadow: style.shadow,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        renderer.fill_text(
+            core::Text {
+                content: "×".to_owned(),
+                bounds: bounds.size(),
+                size: Pixels(20.0),
+                line_height: text::LineHeight::default(),
+                font: self.font.unwrap_or_else(|| renderer.default_font()),
+                align_x: text::Alignment::Center,
+                align_y: alignment::Vertical::Center,
+                shaping: text::Shaping::Advanced,
+                wrapping: text::Wrapping::None,
+            },
+            bounds.center(),
+            style.text_color,
+            *viewport,
+        );
+    }
+
+    /// Returns the overlays of the content and the buttons of the open
+    /// [`Dialog`].
+    ///
+    /// The overlays of the base, given its layout and translation, come
+    /// first when the [`Dialog`] is not modal, since the base can still be
+    /// used.
+    fn overlay_dialog<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        (base_layout, base_translation): (Layout<'b>, Vector),
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let is_modal = self.is_modal();
+        let (base_tree, trees) = tree.children.split_at_mut(1);
+
+        let base = (!is_modal)
+            .then(|| {
+                self.base.as_widget_mut().overlay(
+                    &mut base_tree[0],
+                    base_layout,
+                    renderer,
+                    viewport,
+                    base_translation,
+                )
+            })
+            .flatten();
+
+        let overlays: Vec<_> = base
+            .into_iter()
+            .chain(
+                std::iter::once(&mut self.content)
+                    .chain(&mut self.buttons)
+                    .zip(dialog_children(layout))
+                    .zip(trees)
+                    .filter_map(|((child, layout), tree)| {
+                        child.as_widget_mut().overlay(
+                            tree,
+                            layout,
+                            renderer,
+                            viewport,
+                            translation,
+                        )
+                    }),
+            )
+            .collect();
+
+        (!overlays.is_empty())
+            .then(|| overlay::Group::with_children(overlays).overlay())
+    }
+}
+
+/// The width of the band around the edges of a resizable [`Dialog`] that can
+/// be dragged to resize it.
+const HANDLE_SIZE: f32 = 8.0;
+
+/// A resize of a [`Dialog`] in progress.
+#[derive(Debug, Clone, Copy)]
+struct Resize {
+    handle: Handle,
+    /// Where the resize handle was grabbed.
+    grab: Point,
+    /// The bounds of the [`Dialog`] when the resize started.
+    start: Rectangle,
+}
+
+impl Resize {
+    /// Returns the size of the [`Dialog`] with its handle dragged to the
+    /// given position, within the given area and size constraints.
+    ///
+    /// The dragged edges stop at the edges of the area, so the size never
+    /// grows past what can be drawn.
+    fn size(
+        &self,
+        position: Point,
+        area: Rectangle,
+        min: Size,
+        max: Size,
+    ) -> Size {
+        let delta = position - self.grab;
+        let grow = |edge: Option<Edge>, delta: f32| match edge {
+            Some(Edge::Start) => -delta,
+            Some(Edge::End) => delta,
+            None => 0.0,
+        };
+
+        let available = Size::new(
+            match self.handle.x {
+                Some(Edge::Start) => self.start.x + self.start.width - area.x,
+                Some(Edge::End) => area.x + area.width - self.start.x,
+                None => f32::INFINITY,
+            },
+            match self.handle.y {
+                Some(Edge::Start) => self.start.y + self.start.height - area.y,
+                Some(Edge::End) => area.y + area.height - self.start.y,
+                None => f32::INFINITY,
+            },
+        );
+
+        Size::new(
+            (self.start.width + grow(self.handle.x, delta.x))
+                .min(available.width)
+                .min(max.width)
+                .max(min.width),
+            (self.start.height + grow(self.handle.y, delta.y))
+                .min(available.height)
+                .min(max.height)
+                .max(min.height),
+        )
+    }
+}
+
+/// An edge or a corner of a [`Dialog`] that can be dragged to resize it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Handle {
+    x: Option<Edge>,
+    y: Option<Edge>,
+}
+
+/// A side of a [`Dialog`] along one axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edge {
+    Start,
+    End,
 }
 
-/// The default style of a [`Dialog`].
-pub fn default<Theme>(_theme: &Theme) -> Style {
+impl Handle {
+    /// Returns the [`Handle`] of a [`Dialog`] with the given bounds at the
+    /// given position, if any.
+    fn at(bounds: Rectangle, position: Point) -> Option<Self> {
+        if !bounds.expand(HANDLE_SIZE / 2.0).contains(position) {
+            return None;
+        }
+
+        let edge = |position: f32, start: f32, length: f32| {
+            if (position - start).abs() <= HANDLE_SIZE / 2.0 {
+                Some(Edge::Start)
+            } else if (position - start - length).abs() <= HANDLE_SIZE / 2.0 {
+                Some(Edge::End)
+            } else {
+                None
+            }
+        };
+
+        let handle = Self {
+            x: edge(position.x, bounds.x, bounds.width),
+            y: edge(position.y, bounds.y, bounds.height),
+        };
+
+        (handle.x.is_some() || handle.y.is_some()).then_some(handle)
+    }
+
+    /// Returns the [`mouse::Interaction`] shown over the [`Handle`].
+    fn interaction(self) -> mouse::Interaction {
+        match (self.x, self.y) {
+            (Some(x), Some(y)) if x == y => {
+                mouse::Interaction::ResizingDiagonallyDown
+            }
+            (Some(_), Some(_)) => mouse::Interaction::ResizingDiagonallyUp,
+            (Some(_), None) => mouse::Interaction::ResizingHorizontally,
+            (None, Some(_)) => mouse::Interaction::ResizingVertically,
+            (None, None) => mouse::Interaction::None,
+        }
+    }
+}
+
+/// Returns the bounds of the title area of an open [`Dialog`], which spans
+/// its whole width above the content.
+fn title_area(layout: Layout<'_>) -> Rectangle {
+    let bounds = layout.bounds();
+
+    Rectangle {
+        height: layout.child(1).bounds().y - bounds.y,
+        ..bounds
+    }
+}
+
+/// The marker an open modal [`Dialog`] hands to custom operations, so the
+/// dialogs it is nested in know they are covered.
+struct Covering;
+
+/// Returns whether an open modal [`Dialog`] is found by the [`Operation`]
+/// given to the `operate` closure.
+fn is_covered(mut operate: impl FnMut(&mut dyn Operation)) -> bool {
+    struct Find {
+        is_covered: bool,
+    }
+
+    impl Operation for Find {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            if !self.is_covered {
+                operate(self);
+            }
+        }
+
+        fn container(&mut self, _id: Option<&Id>, _bounds: Rectangle) {}
+
+        fn custom(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            self.is_covered |= state.is::<Covering>();
+        }
+    }
+
+    let mut find = Find { is_covered: false };
+    operate(&mut find);
+
+    find.is_covered
+}
+
+/// Forwards to `shell` what was requested from the given `local` [`Shell`]
+/// of a child, except for its messages and whether it captured the event.
+pub(crate) fn forward<Message, T>(
+    shell: &mut Shell<'_, Message>,
+    local: &Shell<'_, T>,
+) {
+    if local.is_layout_invalid() {
+        shell.invalidate_layout();
+    }
+
+    if local.are_widgets_invalid() {
+        shell.invalidate_widgets();
+    }
+
+    shell.request_redraw_at(local.redraw_request());
+    shell.request_input_method(local.input_method());
+}
+
+/// Returns the layouts of the content and the buttons of an open [`Dialog`],
+/// in this order.
+fn dialog_children(layout: Layout<'_>) -> impl Iterator<Item = Layout<'_>> {
+    std::iter::once(layout.child(1)).chain(layout.child(2).children())
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Dialog<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        // The children are kept regardless of `is_open`, so that toggling the
+        // dialog never shifts or resets their state.
+        [&self.base, &self.content]
+            .into_iter()
+            .chain(&self.buttons)
+            .map(Tree::new)
+            .collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let children: Vec<_> = [&self.base, &self.content]
+            .into_iter()
+            .chain(&self.buttons)
+            .collect();
+
+        tree.diff_children(&children);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.base.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let base = self.base.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        );
+
+        let size = base.size();
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        // When covering the window, the dialog is laid out by its overlay.
+        let area = (!self.cover_window && self.is_visible(state))
+            .then(|| self.layout_area(tree, renderer, size));
+
+        layout::Node::with_children(
+            size,
+            std::iter::once(base).chain(area).collect(),
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut layouts = layout.children();
+        let base_layout = layouts.next().unwrap();
+        let area_layout = layouts.next().filter(|_| self.is_open);
+
+        if self.is_open && self.is_modal() {
+            operation.custom(None, layout.bounds(), &mut Covering);
+        }
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            focus::base(
+                self.is_open && self.is_modal(),
+                operation,
+                |operation| {
+                    self.base.as_widget_mut().operate(
+                        &mut tree.children[0],
+                        base_layout,
+                        renderer,
+                        operation,
+                    );
+                },
+            );
+
+            if let Some(area_layout) = area_layout {
+                self.operate_dialog(
+                    &mut tree.children[1..],
+                    area_layout.child(0),
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let mut layouts = layout.children();
+        let base_layout = layouts.next().unwrap();
+
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            state.now = *now;
+
+            if self.is_animated() {
+                if state.animation.value() != self.is_open {
+                    state.animation = state
+                        .animation
+                        .clone()
+                        .duration(self.duration)
+                        .easing(self.easing);
+                    state.animation.go_mut(self.is_open, *now);
+                }
+
+                if state.animation.is_animating(*now) {
+                    shell.request_redraw();
+                } else if !self.is_open && layout.children().len() > 1 {
+                    shell.invalidate_layout();
+                }
+            }
+        }
+
+        if !self.is_open {
+            state.focus.close(|operation| {
+                self.base.as_widget_mut().operate(
+                    &mut tree.children[0],
+                    base_layout,
+                    renderer,
+                    operation,
+                );
+            });
+
+            self.base.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                base_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            return;
+        }
+
+        // When covering the window, the overlay handles the dialog itself.
+        if let Some(area_layout) = layouts.next() {
+            overlay::Overlay::update(
+                &mut Overlay {
+                    dialog: self,
+                    tree,
+                    base_layout,
+                    translation: Vector::ZERO,
+                },
+                event,
+                area_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+            );
+        }
+
+        if matches!(event, Event::Window(_))
+            || !self.is_modal() && !shell.is_event_captured()
+        {
+            let cursor = self.base_cursor(layout, cursor);
+
+            self.base.as_widget_mut().update(
+                &mut tree.children[0],
+                event,
+                base_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut layouts = layout.children();
+        let base_layout = layouts.next().unwrap();
+
+        if !self.is_open {
+            return self.base.as_widget().mouse_interaction(
+                &tree.children[0],
+                base_layout,
+                cursor,
+                viewport,
+                renderer,
+            );
+        }
+
+        let interaction =
+            layouts
+                .next()
+                .map_or(mouse::Interaction::None, |area_layout| {
+                    self.mouse_interaction_area(
+                        tree,
+                        area_layout,
+                        cursor,
+                        viewport,
+                        renderer,
+                    )
+                });
+
+        if interaction == mouse::Interaction::None && !self.is_modal() {
+            self.base.as_widget().mouse_interaction(
+                &tree.children[0],
+                base_layout,
+                self.base_cursor(layout, cursor),
+                viewport,
+                renderer,
+            )
+        } else {
+            interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let mut layouts = layout.children();
+        let base_layout = layouts.next().unwrap();
+
+        self.base.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            base_layout,
+            if self.is_open {
+                self.base_cursor(layout, cursor)
+            } else {
+                cursor
+            },
+            viewport,
+        );
+
+        if let Some(area_layout) = layouts.next() {
+            self.draw_area(tree, renderer, theme, style, area_layout, cursor);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let mut layouts = layout.children();
+        let base_layout = layouts.next().unwrap();
+
+        if self.cover_window && self.is_visible(state) {
+            return Some(overlay::Element::new(Box::new(Overlay {
+                dialog: self,
+                tree,
+                base_layout,
+                translation,
+            })));
+        }
+
+        let Some(area_layout) = layouts.next().filter(|_| self.is_open) else {
+            return self.base.as_widget_mut().overlay(
+                &mut tree.children[0],
+                base_layout,
+                renderer,
+                viewport,
+                translation,
+            );
+        };
+
+        self.overlay_dialog(
+            tree,
+            (base_layout, translation),
+            area_layout.child(0),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+/// The backdrop and the dialog of an open [`Dialog`].
+///
+/// It is drawn on top of everything else when the [`Dialog`] covers the
+/// window, and it handles the events of the open [`Dialog`] in both cases.
+struct Overlay<'a, 'b, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::text::Renderer,
+    Theme: 'a + Catalog,
+{
+    dialog: &'b mut Dialog<'a, Message, Theme, Renderer>,
+    tree: &'b mut Tree,
+    base_layout: Layout<'b>,
+    /// The translation of the base, for its own overlays.
+    translation: Vector,
+}
+
+impl<'a, Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
+    for Overlay<'a, '_, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog,
+{
+    fn layout(&mut self, renderer: &Renderer, bounds: Size) -> layout::Node {
+        self.dialog.layout_area(self.tree, renderer, bounds)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+    ) {
+        self.dialog
+            .draw_area(self.tree, renderer, theme, style, layout, cursor);
+    }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if !self.dialog.is_open {
+            return;
+        }
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.dialog.operate_dialog(
+                &mut self.tree.children[1..],
+                layout.child(0),
+                renderer,
+                operation,
+            );
+        });
+    }
+
+    fn update(
+        &mut self,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if !self.dialog.is_open {
+            return;
+        }
+
+        let state =
+            self.tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let (base_tree, children) = self.tree.children.split_at_mut(1);
+        let base_tree = &mut base_tree[0];
+        let bounds = layout.bounds();
+        let dialog_layout = layout.child(0);
+
+        // Only a modal dialog takes the focus from the base; the others leave
+        // it where it is until they are clicked or focused by the application.
+        if self.dialog.is_modal() {
+            let is_opening = state.focus.open(|operation| {
+                self.dialog.base.as_widget_mut().operate(
+                    base_tree,
+                    self.base_layout,
+                    renderer,
+                    operation,
+                );
+            });
+
+            if is_opening || state.id != self.dialog.id {
+                state.id.clone_from(&self.dialog.id);
+
+                self.dialog.operate_dialog(
+                    children,
+                    dialog_layout,
+                    renderer,
+                    &mut focus::focus(focus::Target::Index(0)),
+                );
+            }
+        }
+
+        // Escape is handled before the widgets of the dialog, since a focused
+        // text input would otherwise take it to unfocus itself.
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Named(keyboard::key::Named::Escape),
+            ..
+        }) = event
+        {
+            // A dialog open on top of this one may have taken it already.
+            if shell.is_event_captured() {
+                return;
+            }
+
+            if (self.dialog.is_modal()
+                || focus::find(|operation| {
+                    self.dialog.operate_dialog(
+                        children,
+                        dialog_layout,
+                        renderer,
+                        operation,
+                    );
+                })
+                .is_some())
+                && let Some(on_escape) = self.dialog.on_escape.as_ref().or(self
+                    .dialog
+                    .on_close
+                    .as_ref()
+                    .filter(|_| self.dialog.is_closable()))
+            {
+                shell.publish(on_escape());
+                shell.capture_event();
+                return;
+            }
+        }
+
+        for ((child, tree), layout) in std::iter::once(&mut self.dialog.content)
+            .chain(&mut self.dialog.buttons)
+            .zip(children.iter_mut())
+            .zip(dialog_children(dialog_layout))
+        {
+            child.as_widget_mut().update(
+                tree, event, layout, cursor, renderer, clipboard, shell,
+                &bounds,
+            );
+        }
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            state.is_covered = is_covered(|operation| {
+                self.dialog.operate_dialog(
+                    children,
+                    dialog_layout,
+                    renderer,
+                    operation,
+                );
+            });
+        }
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        // A dialog that is not modal leaves the keyboard to the base unless
+        // it has the focus.
+        if !self.dialog.is_modal()
+            && matches!(event, Event::Keyboard(_))
+            && focus::find(|operation| {
+                self.dialog.operate_dialog(
+                    children,
+                    dialog_layout,
+                    renderer,
+                    operation,
+                );
+            })
+            .is_none()
+        {
+            return;
+        }
+
+        let blocked = if self.dialog.is_modal() {
+            bounds
+        } else {
+            dialog_layout.bounds()
+        };
+
+        match event {
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Tab),
+                modifiers,
+                ..
+            }) => {
+                focus::cycle(modifiers.shift(), |operation| {
+                    self.dialog.operate_dialog(
+                        children,
+                        dialog_layout,
+                        renderer,
+                        operation,
+                    );
+                });
+
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: keyboard::Key::Named(keyboard::key::Named::Enter),
+                ..
+            }) => {
+                if let Some(on_enter) = &self.dialog.on_enter {
+                    shell.publish(on_enter(self.dialog.is_checked));
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if self.dialog.is_closable()
+                    && cursor.is_over(dialog_layout.child(3).bounds())
+                {
+                    state.is_close_pressed = true;
+                    shell.capture_event();
+                    shell.request_redraw();
+                } else if self.dialog.is_resizable
+                    && let Some(position) = cursor.position()
+                    && let Some(handle) =
+                        Handle::at(dialog_layout.bounds(), position)
+                {
+                    state.resize = Some(Resize {
+                        handle,
+                        grab: position,
+                        start: dialog_layout.bounds(),
+                    });
+                    shell.capture_event();
+                } else if self.dialog.is_draggable
+                    && let Some(position) =
+                        cursor.position_over(title_area(dialog_layout))
+                {
+                    state.grab = Some(position - state.offset);
+                    shell.capture_event();
+                } else if cursor.is_over(dialog_layout.bounds()) {
+                    shell.capture_event();
+                } else if self.dialog.is_modal() && cursor.is_over(bounds) {
+                    if let Some(on_press) = &self.dialog.on_press {
+                        shell.publish(on_press());
+                    }
+
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if state.is_close_pressed =>
+            {
+                state.is_close_pressed = false;
+
+                if let Some(on_close) = &self.dialog.on_close
+                    && cursor.is_over(dialog_layout.child(3).bounds())
+                {
+                    shell.publish(on_close());
+                }
+
+                shell.capture_event();
+                shell.request_redraw();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.grab.is_some() =>
+            {
+                if let Some(grab) = state.grab {
+                    state.offset = *position - grab;
+                }
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. })
+                if state.resize.is_some() =>
+            {
+                if let Some(resize) = state.resize {
+                    let dialog = &self.dialog;
+
+                    let min = Size::new(
+                        dialog
+                            .min_width
+                            .unwrap_or(0.0)
+                            .max(dialog.padding_inner.x()),
+                        dialog
+                            .min_height
+                            .unwrap_or(0.0)
+                            .max(dialog.padding_inner.y()),
+                    );
+                    let max = Size::new(
+                        dialog.max_width.unwrap_or(f32::INFINITY),
+                        dialog.max_height.unwrap_or(f32::INFINITY),
+                    );
+
+                    state.size = Some(resize.size(
+                        *position,
+                        bounds.shrink(dialog.padding_outer),
+                        min,
+                        max,
+                    ));
+                }
+
+                shell.invalidate_layout();
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+                if state.grab.is_some() || state.resize.is_some() =>
+            {
+                state.grab = None;
+                state.resize = None;
+                shell.capture_event();
+            }
+            Event::Mouse(_) | Event::Touch(_) if cursor.is_over(blocked) => {
+                shell.capture_event();
+            }
+            _ => {}
+        }
+
+        // Nothing but a modal dialog may react to the keyboard when it covers
+        // the window.
+        if self.dialog.cover_window
+            && self.dialog.is_modal()
+            && matches!(event, Event::Keyboard(_))
+        {
+            shell.capture_event();
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.dialog.is_open {
+            return mouse::Interaction::None;
+        }
+
+        // The runtime only gives the cursor to the base when no interaction
+        // is shown, so a dialog that is not modal must show none outside of
+        // its own bounds.
+        self.dialog.mouse_interaction_area(
+            self.tree,
+            layout,
+            cursor,
+            &layout.bounds(),
+            renderer,
+        )
+    }
+
+    fn overlay<'c>(
+        &'c mut self,
+        layout: Layout<'c>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'c, Message, Theme, Renderer>> {
+        if !self.dialog.is_open {
+            return None;
+        }
+
+        self.dialog.overlay_dialog(
+            self.tree,
+            (self.base_layout, self.translation),
+            layout.child(0),
+            renderer,
+            &layout.bounds(),
+            Vector::ZERO,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Dialog<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog,
+    Message: 'a + Clone,
+{
+    fn from(mut dialog: Dialog<'a, Message, Theme, Renderer>) -> Self {
+        dialog.build_checked_buttons();
+
+        Element::new(dialog)
+    }
+}
+
+/// A button of a [`Dialog`] whose message is built from whether the
+/// checkbox of the [`Dialog`] is checked.
+///
+/// It is built once the [`Dialog`] is turned into an [`Element`], so that
+/// the checkbox can be added after it.
+struct CheckedButton<'a, Message> {
+    /// The position of the button among the buttons of the [`Dialog`].
+    index: usize,
+    content: &'a str,
+    on_press: Rc<dyn Fn(bool) -> Message + 'a>,
+    is_default: bool,
+}
+
+/// The possible status of a [`Dialog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`Dialog`] is open and receives input.
+    Active,
+    /// The [`Dialog`] is underneath another modal [`Dialog`] open in its
+    /// content.
+    Inactive,
+    /// The [`Dialog`] is being moved or resized by the user.
+    Dragging,
+    /// The opening [`Transition`] of the [`Dialog`] is being played.
+    Opening,
+    /// The closing [`Transition`] of the [`Dialog`] is being played.
+    Closing,
+}
+
+/// The style of a [`Dialog`].
+///
+/// The [container] and [title] classes of a [`Dialog`] are applied on top
+/// of its [`Style`], so they can still override parts of it.
+///
+/// [container]: Dialog::container_class
+/// [title]: Dialog::title_class
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Dialog`]'s backdrop.
+    pub backdrop_color: Color,
+    /// The [`Background`] of the [`Dialog`].
+    pub background: Option<Background>,
+    /// The [`Border`] of the [`Dialog`].
+    pub border: Border,
+    /// The [`Shadow`] of the [`Dialog`].
+    pub shadow: Shadow,
+    /// The color of the [`Dialog`]'s title.
+    pub title_color: Option<Color>,
+    /// The color of the text in the [`Dialog`]'s body.
+    pub text_color: Option<Color>,
+    /// The [`Background`] of the [`Dialog`]'s footer, behind its buttons.
+    pub footer_background: Option<Background>,
+}
+
+impl Style {
+    /// Creates a [`Style`] with the given backdrop color and nothing else.
+    pub fn backdrop(color: impl Into<Color>) -> Self {
+        Self {
+            backdrop_color: color.into(),
+            background: None,
+            border: Border::default(),
+            shadow: Shadow::default(),
+            title_color: None,
+            text_color: None,
+            footer_background: None,
+        }
+    }
+}
+
+/// The theme catalog of a [`Dialog`].
+pub trait Catalog:
+    text::Catalog + container::Catalog + button::Catalog
+{
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The default class for the [`Dialog`]'s title.
+    fn default_title<'a>() -> <Self as text::Catalog>::Class<'a> {
+        <Self as text::Catalog>::default()
+    }
+
+    /// The default class for the [`Dialog`]'s container.
+    fn default_container<'a>() -> <Self as container::Catalog>::Class<'a> {
+        <Self as container::Catalog>::default()
+    }
+
+    /// The default class for the buttons made with [`button`](crate::button),
+    /// and the other buttons of a [`Dialog`] than its default one.
+    fn default_button<'a>() -> <Self as button::Catalog>::Class<'a> {
+        <Self as button::Catalog>::default()
+    }
+
+    /// The default class for the [`Dialog`]'s default button.
+    fn default_accent_button<'a>() -> <Self as button::Catalog>::Class<'a> {
+        <Self as button::Catalog>::default()
+    }
+
+    /// The default class for the [`Dialog`]'s close button.
+    fn default_close_button<'a>() -> <Self as button::Catalog>::Class<'a> {
+        <Self as button::Catalog>::default()
+    }
+
+    /// The default class for the validation errors of a [`Prompt`].
+    fn default_validation<'a>() -> <Self as text::Catalog>::Class<'a> {
+        <Self as text::Catalog>::default()
+    }
+
+    /// The [`Style`] of a class with the given [`Status`].
+    fn style(
+        &self,
+        class: &<Self as Catalog>::Class<'_>,
+        status: Status,
+    ) -> Style;
+
+    /// The colors of the icon of an [`Alert`] with the given [`Severity`].
+    fn severity(&self, severity: Severity) -> palette::Pair;
+}
+
+/// A styling function for a [`Dialog`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> <Self as Catalog>::Class<'a> {
+        Box::new(default)
+    }
+
+    fn default_button<'a>() -> <Self as button::Catalog>::Class<'a> {
+        Box::new(button::secondary)
+    }
+
+    fn default_accent_button<'a>() -> <Self as button::Catalog>::Class<'a> {
+        Box::new(button::primary)
+    }
+
+    fn default_close_button<'a>() -> <Self as button::Catalog>::Class<'a> {
+        Box::new(button::text)
+    }
+
+    fn default_validation<'a>() -> <Self as text::Catalog>::Class<'a> {
+        Box::new(text::danger)
+    }
+
+    fn style(
+        &self,
+        class: &<Self as Catalog>::Class<'_>,
+        status: Status,
+    ) -> Style {
+        class(self, status)
+    }
+
+    fn severity(&self, severity: Severity) -> palette::Pair {
+        let palette = self.extended_palette();
+
+        match severity {
+            Severity::Info => palette.primary.base,
+            Severity::Success => palette.success.base,
+            Severity::Warning => palette.warning.base,
+            Severity::Error => palette.danger.base,
+        }
+    }
+}
+
+/// The default style of a [`Dialog`], drawn on the background of the
+/// theme.
+///
+/// A [`Dialog`] underneath another one is dimmed as if it were behind a
+/// backdrop.
+pub fn default<Theme>(theme: &Theme, status: Status) -> Style
+where
+    Theme: theme::Base,
+{
+    let backdrop = core::color!(0x000000, 0.3);
+    let background = theme.base().background_color;
+
+    let background = match status {
+        Status::Inactive => Color {
+            r: background.r * (1.0 - backdrop.a),
+            g: background.g * (1.0 - backdrop.a),
+            b: background.b * (1.0 - backdrop.a),
+            ..background
+        },
+        Status::Active
+        | Status::Dragging
+        | Status::Opening
+        | Status::Closing => background,
+    };
+
     Style {
-        backdrop_color: core::color!(0x000000, 0.3),
+        background: Some(background.into()),
+        ..Style::backdrop(backdrop)
     }
 }
@@ -0,0 +1,116 @@
+//! Tell the user something they need to acknowledge.
+use iced_widget::{
+    text,
+    text::{Fragment, IntoFragment},
+};
+
+use crate::core::{self, Element};
+use crate::dialog::{Catalog, Dialog};
+
+/// A [`Dialog`] telling the user something, with a single button to
+/// acknowledge it.
+///
+/// A [`Severity`] icon is shown next to the title. The button, the backdrop
+/// and the Escape key all produce the acknowledge message.
+pub struct Alert<
+    'a,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> {
+    is_open: bool,
+    base: Element<'a, Message, Theme, Renderer>,
+    severity: Severity,
+    title: Fragment<'a>,
+    message: Fragment<'a>,
+    acknowledge_label: &'a str,
+    on_acknowledge: Message,
+}
+
+impl<'a, Message, Theme, Renderer> Alert<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Alert`] with the given base, [`Severity`], title and
+    /// message.
+    pub fn new(
+        is_open: bool,
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        severity: Severity,
+        title: impl IntoFragment<'a>,
+        message: impl IntoFragment<'a>,
+        on_acknowledge: Message,
+    ) -> Self {
+        Self {
+            is_open,
+            base: base.into(),
+            severity,
+            title: title.into_fragment(),
+            message: message.into_fragment(),
+            acknowledge_label: "Ok",
+            on_acknowledge,
+        }
+    }
+
+    /// Sets the label of the acknowledge button.
+    ///
+    /// It is "Ok" by default.
+    pub fn acknowledge_label(mut self, label: &'a str) -> Self {
+        self.acknowledge_label = label;
+        self
+    }
+}
+
+/// The severity of an [`Alert`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// Something the user might want to know.
+    Info,
+    /// Something went well.
+    Success,
+    /// Something might go wrong.
+    Warning,
+    /// Something went wrong.
+    Error,
+}
+
+impl Severity {
+    /// Returns the glyph of the icon of the [`Severity`].
+    pub(crate) fn glyph(self) -> &'static str {
+        match self {
+            Self::Info => "i",
+            Self::Success => "✓",
+            Self::Warning => "!",
+            Self::Error => "×",
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Alert<'a, Message, Theme, Renderer>>
+    for Dialog<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog,
+    Message: 'a + Clone,
+{
+    fn from(alert: Alert<'a, Message, Theme, Renderer>) -> Self {
+        Dialog::new(alert.is_open, alert.base, text(alert.message))
+            .title(alert.title)
+            .severity(alert.severity)
+            .push_default_button(
+                alert.acknowledge_label,
+                alert.on_acknowledge.clone(),
+            )
+            .on_press(alert.on_acknowledge.clone())
+            .on_escape(alert.on_acknowledge)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Alert<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog,
+    Message: 'a + Clone,
+{
+    fn from(alert: Alert<'a, Message, Theme, Renderer>) -> Self {
+        Dialog::from(alert).into()
+    }
+}
@@ -0,0 +1,308 @@
+//! Ask the user to choose among some options.
+use std::rc::Rc;
+
+use iced_widget::{
+    Column, checkbox, container, radio, scrollable, text,
+    text::{Fragment, IntoFragment},
+};
+
+use crate::core::{self, Element, Length, Padding};
+use crate::dialog::{Catalog, Dialog};
+
+/// The indentation of the description of an [`Item`], which lines it up
+/// with the label of its radio or checkbox.
+const DESCRIPTION_INDENT: f32 = 24.0;
+
+/// A [`Dialog`] asking the user to choose among some options.
+///
+/// A [`Choice`] either lets the user pick a [single] option with radios, or
+/// [multiple] options with checkboxes. The options are shown in a scrollable
+/// body, so long lists keep the buttons of the [`Dialog`] in view.
+///
+/// Like any radio or checkbox, a [`Choice`] does not keep its selection: it
+/// shows the given selection and produces a message whenever the user
+/// changes it.
+///
+/// Both buttons, the backdrop and the Escape key produce their message
+/// through a single callback, which receives the selection only when it is
+/// confirmed. A [single] choice cannot be confirmed until an option is
+/// selected.
+///
+/// [single]: Choice::single
+/// [multiple]: Choice::multiple
+pub struct Choice<
+    'a,
+    T,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> {
+    is_open: bool,
+    base: Element<'a, Message, Theme, Renderer>,
+    title: Fragment<'a>,
+    items: Vec<Item<'a, T>>,
+    mode: Mode<'a, T, Message>,
+    confirm_label: &'a str,
+    cancel_label: &'a str,
+}
+
+/// How many options a [`Choice`] lets the user pick.
+enum Mode<'a, T, Message> {
+    Single {
+        selected: Option<T>,
+        on_select: Box<dyn Fn(T) -> Message + 'a>,
+        on_answer: Box<dyn Fn(Option<T>) -> Message + 'a>,
+    },
+    Multiple {
+        selected: Vec<T>,
+        on_toggle: Rc<dyn Fn(T, bool) -> Message + 'a>,
+        on_answer: Box<dyn Fn(Option<Vec<T>>) -> Message + 'a>,
+    },
+}
+
+impl<'a, T, Message, Theme, Renderer> Choice<'a, T, Message, Theme, Renderer> {
+    /// Creates a new [`Choice`] letting the user pick a single option.
+    ///
+    /// `on_select` is called with the value of the option the user selects.
+    pub fn single(
+        is_open: bool,
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        title: impl IntoFragment<'a>,
+        items: impl IntoIterator<Item = Item<'a, T>>,
+        selected: Option<T>,
+        on_select: impl Fn(T) -> Message + 'a,
+        on_answer: impl Fn(Option<T>) -> Message + 'a,
+    ) -> Self {
+        Self::new(
+            is_open,
+            base,
+            title,
+            items,
+            Mode::Single {
+                selected,
+                on_select: Box::new(on_select),
+                on_answer: Box::new(on_answer),
+            },
+        )
+    }
+
+    /// Creates a new [`Choice`] letting the user pick multiple options.
+    ///
+    /// `on_toggle` is called with the value of the option the user checks or
+    /// unchecks, and whether it is now checked. The confirmed values keep
+    /// the order of the options.
+    pub fn multiple(
+        is_open: bool,
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        title: impl IntoFragment<'a>,
+        items: impl IntoIterator<Item = Item<'a, T>>,
+        selected: impl IntoIterator<Item = T>,
+        on_toggle: impl Fn(T, bool) -> Message + 'a,
+        on_answer: impl Fn(Option<Vec<T>>) -> Message + 'a,
+    ) -> Self {
+        Self::new(
+            is_open,
+            base,
+            title,
+            items,
+            Mode::Multiple {
+                selected: selected.into_iter().collect(),
+                on_toggle: Rc::new(on_toggle),
+                on_answer: Box::new(on_answer),
+            },
+        )
+    }
+
+    fn new(
+        is_open: bool,
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        title: impl IntoFragment<'a>,
+        items: impl IntoIterator<Item = Item<'a, T>>,
+        mode: Mode<'a, T, Message>,
+    ) -> Self {
+        Self {
+            is_open,
+            base: base.into(),
+            title: title.into_fragment(),
+            items: items.into_iter().collect(),
+            mode,
+            confirm_label: "Ok",
+            cancel_label: "Cancel",
+        }
+    }
+
+    /// Sets the label of the confirm button.
+    ///
+    /// It is "Ok" by default.
+    pub fn confirm_label(mut self, label: &'a str) -> Self {
+        self.confirm_label = label;
+        self
+    }
+
+    /// Sets the label of the cancel button.
+    ///
+    /// It is "Cancel" by default.
+    pub fn cancel_label(mut self, label: &'a str) -> Self {
+        self.cancel_label = label;
+        self
+    }
+}
+
+/// An option of a [`Choice`].
+#[derive(Debug, Clone)]
+pub struct Item<'a, T> {
+    value: T,
+    label: Fragment<'a>,
+    description: Option<Fragment<'a>>,
+}
+
+impl<'a, T> Item<'a, T> {
+    /// Creates a new [`Item`] with the given value and label.
+    pub fn new(value: T, label: impl IntoFragment<'a>) -> Self {
+        Self {
+            value,
+            label: label.into_fragment(),
+            description: None,
+        }
+    }
+
+    /// Sets the description of the [`Item`], shown as a secondary line
+    /// under its label.
+    pub fn description(mut self, description: impl IntoFragment<'a>) -> Self {
+        self.description = Some(description.into_fragment());
+        self
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<Choice<'a, T, Message, Theme, Renderer>>
+    for Dialog<'a, Message, Theme, Renderer>
+where
+    T: 'a + Clone + PartialEq,
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme:
+        'a + Catalog + checkbox::Catalog + radio::Catalog + scrollable::Catalog,
+    Message: 'a + Clone,
+{
+    fn from(choice: Choice<'a, T, Message, Theme, Renderer>) -> Self {
+        let (options, cancel, answer) = match choice.mode {
+            Mode::Single {
+                selected,
+                on_select,
+                on_answer,
+            } => {
+                let index = selected.as_ref().and_then(|selected| {
+                    choice.items.iter().position(|item| item.value == *selected)
+                });
+
+                let options =
+                    choice.items.iter().enumerate().map(|(i, item)| {
+                        let message = on_select(item.value.clone());
+
+                        option(
+                            radio(item.label.clone(), i, index, |_| message),
+                            item.description.clone(),
+                        )
+                    });
+
+                (
+                    Column::new().extend(options),
+                    on_answer(None),
+                    selected
+                        .filter(|_| index.is_some())
+                        .map(|selected| on_answer(Some(selected))),
+                )
+            }
+            Mode::Multiple {
+                selected,
+                on_toggle,
+                on_answer,
+            } => {
+                let options = choice.items.iter().map(|item| {
+                    let on_toggle = on_toggle.clone();
+                    let value = item.value.clone();
+
+                    option(
+                        checkbox(selected.contains(&item.value))
+                            .label(item.label.clone())
+                            .on_toggle(move |is_checked| {
+                                on_toggle(value.clone(), is_checked)
+                            }),
+                        item.description.clone(),
+                    )
+                });
+
+                let answer = choice
+                    .items
+                    .iter()
+                    .filter(|item| selected.contains(&item.value))
+                    .map(|item| item.value.clone())
+                    .collect();
+
+                (
+                    Column::new().extend(options),
+                    on_answer(None),
+                    Some(on_answer(Some(answer))),
+                )
+            }
+        };
+
+        let content = scrollable(options.spacing(12).width(Length::Fill))
+            .width(Length::Fill);
+
+        let dialog = Dialog::new(choice.is_open, choice.base, content)
+            .title(choice.title)
+            .push_button(crate::button(choice.cancel_label, cancel.clone()))
+            .on_press(cancel.clone())
+            .on_escape(cancel.clone());
+
+        match answer {
+            Some(answer) => {
+                dialog.push_default_button(choice.confirm_label, answer)
+            }
+            None => dialog.push_button(
+                crate::button(choice.confirm_label, cancel)
+                    .on_press_maybe(None)
+                    .class(<Theme as Catalog>::default_accent_button()),
+            ),
+        }
+    }
+}
+
+impl<'a, T, Message, Theme, Renderer>
+    From<Choice<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a + Clone + PartialEq,
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme:
+        'a + Catalog + checkbox::Catalog + radio::Catalog + scrollable::Catalog,
+    Message: 'a + Clone,
+{
+    fn from(choice: Choice<'a, T, Message, Theme, Renderer>) -> Self {
+        Dialog::from(choice).into()
+    }
+}
+
+/// Lays out the radio or checkbox of an [`Item`] with its description.
+fn option<'a, Message, Theme, Renderer>(
+    control: impl Into<Element<'a, Message, Theme, Renderer>>,
+    description: Option<Fragment<'a>>,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::text::Renderer,
+    Theme: 'a + container::Catalog + text::Catalog,
+    Message: 'a,
+{
+    let description = description.map(|description| {
+        container(text(description).size(14))
+            .padding(Padding::ZERO.left(DESCRIPTION_INDENT))
+            .into()
+    });
+
+    Column::new()
+        .spacing(2)
+        .push(control)
+        .extend(description)
+        .into()
+}
@@ -0,0 +1,139 @@
+//! Ask the user to confirm or cancel an action.
+use iced_widget::{
+    checkbox, text,
+    text::{Fragment, IntoFragment},
+};
+
+use crate::core::{self, Element};
+use crate::dialog::{Catalog, Dialog};
+
+/// A [`Dialog`] asking the user to confirm or cancel an action.
+///
+/// Both buttons, the backdrop and the Escape key produce their message
+/// through a single callback, which receives `true` only when the action is
+/// confirmed. The confirm button is the default button of the [`Dialog`].
+pub struct Confirm<
+    'a,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> {
+    is_open: bool,
+    base: Element<'a, Message, Theme, Renderer>,
+    title: Fragment<'a>,
+    message: Fragment<'a>,
+    confirm_label: &'a str,
+    cancel_label: &'a str,
+    on_answer: Box<dyn Fn(bool) -> Message + 'a>,
+    checkbox: Option<Checkbox<'a, Message>>,
+}
+
+/// The footer checkbox of a [`Confirm`].
+struct Checkbox<'a, Message> {
+    label: Fragment<'a>,
+    is_checked: bool,
+    on_toggle: Box<dyn Fn(bool) -> Message + 'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Confirm<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Confirm`] with the given base, title and message.
+    pub fn new(
+        is_open: bool,
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        title: impl IntoFragment<'a>,
+        message: impl IntoFragment<'a>,
+        on_answer: impl Fn(bool) -> Message + 'a,
+    ) -> Self {
+        Self {
+            is_open,
+            base: base.into(),
+            title: title.into_fragment(),
+            message: message.into_fragment(),
+            confirm_label: "Ok",
+            cancel_label: "Cancel",
+            on_answer: Box::new(on_answer),
+            checkbox: None,
+        }
+    }
+
+    /// Sets the label of the confirm button.
+    ///
+    /// It is "Ok" by default.
+    pub fn confirm_label(mut self, label: &'a str) -> Self {
+        self.confirm_label = label;
+        self
+    }
+
+    /// Sets the label of the cancel button.
+    ///
+    /// It is "Cancel" by default.
+    pub fn cancel_label(mut self, label: &'a str) -> Self {
+        self.cancel_label = label;
+        self
+    }
+
+    /// Adds a checkbox to the footer of the [`Confirm`], like a "Don't ask
+    /// again" option.
+    ///
+    /// The checkbox is controlled by the application, which can read its
+    /// state back when it receives the answer of the [`Confirm`].
+    ///
+    /// See [`Dialog::checkbox`].
+    pub fn checkbox(
+        mut self,
+        label: impl IntoFragment<'a>,
+        is_checked: bool,
+        on_toggle: impl Fn(bool) -> Message + 'a,
+    ) -> Self {
+        self.checkbox = Some(Checkbox {
+            label: label.into_fragment(),
+            is_checked,
+            on_toggle: Box::new(on_toggle),
+        });
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Confirm<'a, Message, Theme, Renderer>>
+    for Dialog<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog + checkbox::Catalog,
+    Message: 'a + Clone,
+{
+    fn from(confirm: Confirm<'a, Message, Theme, Renderer>) -> Self {
+        let on_answer = confirm.on_answer;
+
+        let dialog =
+            Dialog::new(confirm.is_open, confirm.base, text(confirm.message))
+                .title(confirm.title)
+                .push_button(crate::button(
+                    confirm.cancel_label,
+                    on_answer(false),
+                ))
+                .push_default_button(confirm.confirm_label, on_answer(true))
+                .on_press(on_answer(false))
+                .on_escape(on_answer(false));
+
+        match confirm.checkbox {
+            Some(checkbox) => dialog.checkbox(
+                checkbox.label,
+                checkbox.is_checked,
+                checkbox.on_toggle,
+            ),
+            None => dialog,
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Confirm<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog + checkbox::Catalog,
+    Message: 'a + Clone,
+{
+    fn from(confirm: Confirm<'a, Message, Theme, Renderer>) -> Self {
+        Dialog::from(confirm).into()
+    }
+}
@@ -0,0 +1,404 @@
+//! Tell the user about an error and the chain of its causes.
+use std::fmt::{Display, Write};
+
+use iced_widget::{
+    Column, text,
+    text::{Fragment, IntoFragment},
+    text_editor::{self, Action, Content, TextEditor},
+};
+
+use crate::core::{
+    self, Element, Event, Font, Layout, Length, Rectangle, Shell, Size, layout,
+    mouse, renderer,
+    widget::{Operation, Tree, Widget, tree},
+};
+use crate::dialog::{
+    Catalog, DEFAULT_MAX_HEIGHT, DEFAULT_MAX_WIDTH, Dialog, forward,
+};
+
+/// The maximum size of an [`Error`] whose details are shown.
+const EXPANDED_MAX_SIZE: Size = Size::new(600.0, 480.0);
+
+/// The text size of the details of an [`Error`].
+const DETAILS_SIZE: f32 = 13.0;
+
+/// A [`Dialog`] telling the user about an error.
+///
+/// An [`Error`] shows the message of the error, and a collapsible "Details"
+/// section listing the chain of its [sources] and, optionally, a
+/// [backtrace]. The details are shown in a monospace font, and can be
+/// scrolled through and selected.
+///
+/// Whether the details are shown is up to the application: an [`Error`]
+/// produces a message whenever the user toggles them, and shows them when
+/// it is [expanded].
+///
+/// The copy button does not write to the clipboard itself: it produces the
+/// copy message with the message and the details of the error, and the
+/// application writes them by returning a [`clipboard::write`] task.
+///
+/// The close button, the backdrop, Enter and Escape produce the close
+/// message, even while the details have the focus.
+///
+/// [sources]: std::error::Error::source
+/// [backtrace]: Error::backtrace
+/// [expanded]: Error::expanded
+/// [`clipboard::write`]: https://docs.iced.rs/iced/clipboard/fn.write.html
+pub struct Error<
+    'a,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> {
+    is_open: bool,
+    base: Element<'a, Message, Theme, Renderer>,
+    title: Fragment<'a>,
+    message: String,
+    details: String,
+    is_expanded: bool,
+    on_toggle: Box<dyn Fn(bool) -> Message + 'a>,
+    on_copy: Box<dyn Fn(String) -> Message + 'a>,
+    on_close: Message,
+    copy_label: &'a str,
+    close_label: &'a str,
+}
+
+impl<'a, Message, Theme, Renderer> Error<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Error`] with the given base, title and error.
+    ///
+    /// `on_toggle` is called with whether the details should be shown when
+    /// the user toggles them, and `on_copy` with the message and the details
+    /// of the error when the user copies them.
+    pub fn new(
+        is_open: bool,
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        title: impl IntoFragment<'a>,
+        error: &dyn std::error::Error,
+        on_toggle: impl Fn(bool) -> Message + 'a,
+        on_copy: impl Fn(String) -> Message + 'a,
+        on_close: Message,
+    ) -> Self {
+        let message = error.to_string();
+        let mut details = message.clone();
+
+        for (i, source) in
+            std::iter::successors(error.source(), |error| error.source())
+                .enumerate()
+        {
+            if i == 0 {
+                details.push_str("\n\nCaused by:");
+            }
+
+            let _ = write!(details, "\n    {i}: {source}");
+        }
+
+        Self {
+            is_open,
+            base: base.into(),
+            title: title.into_fragment(),
+            message,
+            details,
+            is_expanded: false,
+            on_toggle: Box::new(on_toggle),
+            on_copy: Box::new(on_copy),
+            on_close,
+            copy_label: "Copy",
+            close_label: "Close",
+        }
+    }
+
+    /// Appends the given backtrace to the details.
+    pub fn backtrace(mut self, backtrace: impl Display) -> Self {
+        let _ = write!(self.details, "\n\nBacktrace:\n{backtrace}");
+        self
+    }
+
+    /// Sets whether the details of the [`Error`] are shown.
+    ///
+    /// They are hidden by default.
+    pub fn expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    /// Sets the label of the copy button.
+    ///
+    /// It is "Copy" by default.
+    pub fn copy_label(mut self, label: &'a str) -> Self {
+        self.copy_label = label;
+        self
+    }
+
+    /// Sets the label of the close button.
+    ///
+    /// It is "Close" by default.
+    pub fn close_label(mut self, label: &'a str) -> Self {
+        self.close_label = label;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Error<'a, Message, Theme, Renderer>>
+    for Dialog<'a, Message, Theme, Renderer>
+where
+    Renderer: 'static + core::Renderer + core::text::Renderer,
+    Renderer::Font: From<Font>,
+    Theme: 'a + Catalog + text_editor::Catalog,
+    Message: 'a + Clone,
+{
+    fn from(error: Error<'a, Message, Theme, Renderer>) -> Self {
+        let toggle = crate::button(
+            if error.is_expanded {
+                "Hide details"
+            } else {
+                "Show details"
+            },
+            (error.on_toggle)(!error.is_expanded),
+        )
+        .width(Length::Shrink);
+
+        let details = error
+            .is_expanded
+            .then(|| Element::new(Details::new(error.details.clone())));
+
+        let content = Column::new()
+            .spacing(12)
+            .height(Length::Fill)
+            .push(text(error.message))
+            .push(toggle)
+            .extend(details);
+
+        let max_size = if error.is_expanded {
+            EXPANDED_MAX_SIZE
+        } else {
+            Size::new(DEFAULT_MAX_WIDTH, DEFAULT_MAX_HEIGHT)
+        };
+
+        Dialog::new(error.is_open, error.base, content)
+            .title(error.title)
+            .max_width(max_size.width)
+            .max_height(max_size.height)
+            .push_button(crate::button(
+                error.copy_label,
+                (error.on_copy)(error.details),
+            ))
+            .push_default_button(error.close_label, error.on_close.clone())
+            .on_press(error.on_close.clone())
+            .on_escape(error.on_close)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Error<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'static + core::Renderer + core::text::Renderer,
+    Renderer::Font: From<Font>,
+    Theme: 'a + Catalog + text_editor::Catalog,
+    Message: 'a + Clone,
+{
+    fn from(error: Error<'a, Message, Theme, Renderer>) -> Self {
+        Dialog::from(error).into()
+    }
+}
+
+/// The details of an [`Error`], shown in a read-only [`TextEditor`].
+///
+/// The [`Content`] of the editor is kept in the widget tree, so the text can
+/// be selected and scrolled through without involving the application.
+struct Details {
+    text: String,
+}
+
+/// The state of the [`Details`] of an [`Error`].
+struct State<Renderer>
+where
+    Renderer: core::text::Renderer,
+{
+    text: String,
+    content: Content<Renderer>,
+    editor: Tree,
+}
+
+impl Details {
+    fn new(text: String) -> Self {
+        Self { text }
+    }
+
+    /// Produces the [`TextEditor`] showing the given [`Content`].
+    fn editor<Theme, Renderer>(
+        content: &Content<Renderer>,
+    ) -> TextEditor<
+        '_,
+        core::text::highlighter::PlainText,
+        Action,
+        Theme,
+        Renderer,
+    >
+    where
+        Theme: text_editor::Catalog,
+        Renderer: core::text::Renderer,
+        Renderer::Font: From<Font>,
+    {
+        TextEditor::new(content)
+            .on_action(|action| action)
+            .font(Font::MONOSPACE)
+            .size(DETAILS_SIZE)
+            .height(Length::Fill)
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Details
+where
+    Theme: text_editor::Catalog,
+    Renderer: 'static + core::text::Renderer,
+    Renderer::Font: From<Font>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer>>()
+    }
+
+    fn state(&self) -> tree::State {
+        let content = Content::with_text(&self.text);
+        let editor = Tree::new(&Self::editor::<Theme, Renderer>(&content)
+            as &dyn Widget<Action, Theme, Renderer>);
+
+        tree::State::new(State {
+            text: self.text.clone(),
+            content,
+            editor,
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let state = tree.state.downcast_mut::<State<Renderer>>();
+
+        if state.text != self.text {
+            state.text.clone_from(&self.text);
+            state.content = Content::with_text(&self.text);
+        }
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fill)
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer>>();
+
+        Self::editor::<Theme, Renderer>(&state.content).layout(
+            &mut state.editor,
+            renderer,
+            limits,
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer>>();
+
+        Self::editor::<Theme, Renderer>(&state.content).operate(
+            &mut state.editor,
+            layout,
+            renderer,
+            operation,
+        );
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn core::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer>>();
+        let mut actions = Vec::new();
+        let mut local = Shell::new(&mut actions);
+
+        Self::editor::<Theme, Renderer>(&state.content).update(
+            &mut state.editor,
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local,
+            viewport,
+        );
+
+        let is_captured = local.is_event_captured();
+        forward(shell, &local);
+
+        // The details are read-only, so the events the editor turns into
+        // edits are left to the dialog, like Enter to close it.
+        let is_edit =
+            !actions.is_empty() && actions.iter().all(Action::is_edit);
+
+        if is_captured && !is_edit {
+            shell.capture_event();
+        }
+
+        for action in actions {
+            if !action.is_edit() {
+                state.content.perform(action);
+                shell.request_redraw();
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer>>();
+
+        Self::editor::<Theme, Renderer>(&state.content).draw(
+            &state.editor,
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer>>();
+
+        Self::editor::<Theme, Renderer>(&state.content).mouse_interaction(
+            &state.editor,
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+}
@@ -0,0 +1,248 @@
+//! Confine the keyboard focus to an open [`Dialog`].
+//!
+//! [`Dialog`]: super::Dialog
+use std::any::Any;
+
+use crate::core::widget::Id;
+use crate::core::widget::operation::{
+    self, Focusable, Operation, Outcome, Scrollable, TextInput, focusable,
+};
+use crate::core::{Rectangle, Vector};
+
+/// A focusable widget of a widget tree.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    /// The widget with the given [`Id`].
+    Id(Id),
+    /// The focusable widget at the given index, for widgets without an
+    /// [`Id`].
+    Index(usize),
+}
+
+/// The focus of the base of a modal dialog.
+///
+/// The focus is taken away from the base when the dialog opens, and given
+/// back to the widget that had it when the dialog closes.
+#[derive(Debug, Default)]
+pub struct Trap {
+    is_open: bool,
+    /// The widget of the base that was focused before the dialog opened.
+    focused: Option<Target>,
+}
+
+impl Trap {
+    /// Takes the focus away from the base, unless the dialog was already
+    /// open, and returns whether the dialog just opened.
+    ///
+    /// The `operate` closure is called with the [`Operation`]s that need to
+    /// run on the base.
+    pub fn open(
+        &mut self,
+        mut operate: impl FnMut(&mut dyn Operation),
+    ) -> bool {
+        if self.is_open {
+            return false;
+        }
+
+        self.is_open = true;
+        self.focused = find(&mut operate);
+        operate(&mut focusable::unfocus());
+
+        true
+    }
+
+    /// Gives the focus back to the base, if the dialog was open.
+    ///
+    /// The `operate` closure is called with the [`Operation`] that needs to
+    /// run on the base.
+    pub fn close(&mut self, mut operate: impl FnMut(&mut dyn Operation)) {
+        if !std::mem::take(&mut self.is_open) {
+            return;
+        }
+
+        if let Some(focused) = self.focused.take() {
+            operate(&mut focus(focused));
+        }
+    }
+}
+
+/// Runs the [`Operation`] on the base of a dialog through `operate`, hiding
+/// the focusable widgets of the base while the dialog is open.
+pub fn base(
+    is_open: bool,
+    operation: &mut dyn Operation,
+    operate: impl FnOnce(&mut dyn Operation),
+) {
+    if is_open {
+        operate(&mut hide(operation));
+    } else {
+        operate(operation);
+    }
+}
+
+/// Returns the focused widget, if any.
+///
+/// The widget is found by its [`Id`] when it has one, so that it can still
+/// be found after other widgets are added to or removed from the tree.
+///
+/// The `operate` closure is called with the [`Operation`] that needs to run
+/// on the widget tree the focused widget should be searched in.
+pub fn find(mut operate: impl FnMut(&mut dyn Operation)) -> Option<Target> {
+    struct Find {
+        index: usize,
+        focused: Option<Target>,
+    }
+
+    impl Operation for Find {
+        fn focusable(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            if self.focused.is_none() && state.is_focused() {
+                self.focused = Some(
+                    id.cloned().map_or(Target::Index(self.index), Target::Id),
+                );
+            }
+
+            self.index += 1;
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+    }
+
+    let mut find = Find {
+        index: 0,
+        focused: None,
+    };
+    operate(&mut find);
+
+    find.focused
+}
+
+/// Moves the focus to the next focusable widget, or to the previous one if
+/// `backwards` is `true`, wrapping around at the ends.
+///
+/// The `operate` closure is called with the [`Operation`]s that need to run
+/// on the widget tree the focus should be cycled in.
+pub fn cycle(backwards: bool, mut operate: impl FnMut(&mut dyn Operation)) {
+    let mut count = focusable::count();
+    operate(&mut operation::black_box(&mut count));
+
+    let Outcome::Some(count) = count.finish() else {
+        return;
+    };
+
+    if count.total == 0 {
+        return;
+    }
+
+    let index = match (count.focused, backwards) {
+        (None, false) => 0,
+        (None, true) => count.total - 1,
+        (Some(focused), false) => (focused + 1) % count.total,
+        (Some(focused), true) => (focused + count.total - 1) % count.total,
+    };
+
+    operate(&mut focus(Target::Index(index)));
+}
+
+/// Produces an [`Operation`] that focuses the given [`Target`] and
+/// unfocuses every other focusable widget.
+pub fn focus(target: Target) -> impl Operation {
+    struct Focus {
+        target: Target,
+        current: usize,
+    }
+
+    impl Operation for Focus {
+        fn focusable(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            state: &mut dyn Focusable,
+        ) {
+            let is_target = match &self.target {
+                Target::Id(target) => id == Some(target),
+                Target::Index(index) => self.current == *index,
+            };
+
+            if is_target {
+                state.focus();
+            } else {
+                state.unfocus();
+            }
+
+            self.current += 1;
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            operate(self);
+        }
+    }
+
+    Focus { target, current: 0 }
+}
+
+/// Wraps the [`Operation`] so that it skips every focusable widget.
+fn hide<'a>(operation: &'a mut dyn Operation) -> impl Operation + 'a {
+    struct Hide<'a> {
+        operation: &'a mut dyn Operation,
+    }
+
+    impl Operation for Hide<'_> {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+            self.operation.traverse(&mut |operation| {
+                operate(&mut Hide { operation });
+            });
+        }
+
+        fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            self.operation.container(id, bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            translation: Vector,
+            state: &mut dyn Scrollable,
+        ) {
+            self.operation.scrollable(
+                id,
+                bounds,
+                content_bounds,
+                translation,
+                state,
+            );
+        }
+
+        fn text_input(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            state: &mut dyn TextInput,
+        ) {
+            self.operation.text_input(id, bounds, state);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
+        fn custom(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            state: &mut dyn Any,
+        ) {
+            self.operation.custom(id, bounds, state);
+        }
+    }
+
+    Hide { operation }
+}
@@ -0,0 +1,451 @@
+//! Ask the user something and await their answer in a [`Task`].
+use std::any::Any;
+use std::collections::VecDeque;
+
+use iced_runtime::futures::futures::channel::oneshot;
+use iced_runtime::{Task, task};
+use iced_widget::Column;
+
+use crate::core::{
+    self, Clipboard, Element, Event, Layout, Length, Rectangle, Shell, Size,
+    layout, mouse, overlay, renderer,
+    widget::{Id, Operation, Tree, Widget, tree},
+};
+use crate::dialog::{self, Catalog, focus, queue::Entry};
+
+/// Shows the dialogs requested with [`ask`] and [`confirm`] over its base.
+///
+/// A [`Host`] needs to be installed once, usually at the root of the view of
+/// your application.
+pub struct Host<
+    'a,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> {
+    base: Element<'a, Message, Theme, Renderer>,
+}
+
+impl<'a, Message, Theme, Renderer> Host<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Host`] with the given base.
+    pub fn new(base: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self { base: base.into() }
+    }
+}
+
+/// Shows the given [`Entry`] in the [`Host`] and produces the message of the
+/// action the user picked.
+///
+/// The [`Task`] produces nothing if there is no [`Host`] in the widget tree.
+pub fn ask<T>(entry: Entry<T>) -> Task<T>
+where
+    T: Send + 'static,
+{
+    let mut answers = Vec::new();
+    let entry = entry.map(|answer| {
+        answers.push(answer);
+        answers.len() - 1
+    });
+
+    let (sender, receiver) = oneshot::channel();
+
+    let request = Request {
+        entry,
+        respond: Box::new(move |index| {
+            if let Some(answer) = answers.into_iter().nth(index) {
+                let _ = sender.send(answer);
+            }
+        }),
+    };
+
+    task::widget(Deliver {
+        request: Some(request),
+    })
+    .discard()
+    .chain(Task::future(receiver).map(Result::ok))
+    .and_then(Task::done)
+}
+
+/// Asks the user to confirm something, producing `true` if they did.
+///
+/// See [`ask`] for details.
+pub fn confirm(
+    title: impl Into<String>,
+    body: impl Into<String>,
+) -> Task<bool> {
+    ask(Entry::new(body)
+        .title(title)
+        .button("Cancel", false)
+        .default_button("Ok", true)
+        .on_escape(false))
+}
+
+/// A dialog requested with [`ask`].
+struct Request {
+    entry: Entry<usize>,
+    respond: Box<dyn FnOnce(usize) + Send>,
+}
+
+/// The internal state of a [`Host`].
+#[derive(Default)]
+struct State {
+    requests: VecDeque<Request>,
+    focus: focus::Trap,
+}
+
+/// An [`Operation`] that hands a [`Request`] over to the first [`Host`].
+struct Deliver {
+    request: Option<Request>,
+}
+
+impl Operation for Deliver {
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation)) {
+        if self.request.is_some() {
+            operate(self);
+        }
+    }
+
+    fn custom(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        state: &mut dyn Any,
+    ) {
+        if let Some(state) = state.downcast_mut::<State>() {
+            state.requests.extend(self.request.take());
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Host<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog,
+{
+    /// Returns the open [`Dialog`] of the given [`Request`].
+    ///
+    /// [`Dialog`]: crate::Dialog
+    fn dialog<'b>(request: &'b Request) -> Element<'b, usize, Theme, Renderer>
+    where
+        Theme: 'b,
+        Renderer: 'b,
+    {
+        request
+            .entry
+            .view(Column::new().width(Length::Fill).height(Length::Fill))
+            .into()
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Host<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.base), Tree::empty()]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.children[0].diff(&self.base);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.base.as_widget().size()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let base = self.base.as_widget_mut().layout(
+            &mut tree.children[0],
+            renderer,
+            limits,
+        );
+
+        let size = base.size();
+        let state = tree.state.downcast_ref::<State>();
+
+        let dialog = state.requests.front().map(|request| {
+            let mut dialog = Self::dialog(request);
+            tree.children[1].diff(&dialog);
+
+            dialog.as_widget_mut().layout(
+                &mut tree.children[1],
+                renderer,
+                &layout::Limits::new(Size::ZERO, size),
+            )
+        });
+
+        layout::Node::with_children(
+            size,
+            std::iter::once(base).chain(dialog).collect(),
+        )
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let mut layouts = layout.children();
+        let base_layout = layouts.next().unwrap();
+        let dialog_layout = layouts.next();
+
+        operation.custom(
+            None,
+            layout.bounds(),
+            tree.state.downcast_mut::<State>(),
+        );
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            let state = tree.state.downcast_ref::<State>();
+            let request = state.requests.front().zip(dialog_layout);
+
+            focus::base(request.is_some(), operation, |operation| {
+                self.base.as_widget_mut().operate(
+                    &mut tree.children[0],
+                    base_layout,
+                    renderer,
+                    operation,
+                );
+            });
+
+            if let Some((request, dialog_layout)) = request {
+                Self::dialog(request).as_widget_mut().operate(
+                    &mut tree.children[1],
+                    dialog_layout,
+                    renderer,
+                    operation,
+                );
+            }
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        let (base_tree, children) = tree.children.split_at_mut(1);
+        let base_tree = &mut base_tree[0];
+        let mut layouts = layout.children();
+        let base_layout = layouts.next().unwrap();
+        let dialog_layout = layouts.next();
+
+        // Requests are delivered by operations, which do not lay out the
+        // widget tree again.
+        if state.requests.is_empty() == dialog_layout.is_some() {
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        let Some((request, dialog_layout)) =
+            state.requests.front().zip(dialog_layout)
+        else {
+            state.focus.close(|operation| {
+                self.base.as_widget_mut().operate(
+                    base_tree,
+                    base_layout,
+                    renderer,
+                    operation,
+                );
+            });
+
+            self.base.as_widget_mut().update(
+                base_tree,
+                event,
+                base_layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+
+            return;
+        };
+
+        let _ = state.focus.open(|operation| {
+            self.base.as_widget_mut().operate(
+                base_tree,
+                base_layout,
+                renderer,
+                operation,
+            );
+        });
+
+        let mut answers = Vec::new();
+        let mut local = Shell::new(&mut answers);
+
+        Self::dialog(request).as_widget_mut().update(
+            &mut children[0],
+            event,
+            dialog_layout,
+            cursor,
+            renderer,
+            clipboard,
+            &mut local,
+            viewport,
+        );
+
+        if local.is_event_captured() {
+            shell.capture_event();
+        }
+
+        dialog::forward(shell, &local);
+
+        if let Some(answer) = answers.pop() {
+            if let Some(request) = state.requests.pop_front() {
+                (request.respond)(answer);
+            }
+
+            shell.invalidate_layout();
+            shell.request_redraw();
+        }
+
+        if let Event::Window(_) = event {
+            self.base.as_widget_mut().update(
+                base_tree,
+                event,
+                base_layout,
+                mouse::Cursor::Unavailable,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State>();
+        let mut layouts = layout.children();
+        let base_layout = layouts.next().unwrap();
+
+        let Some((request, dialog_layout)) =
+            state.requests.front().zip(layouts.next())
+        else {
+            return self.base.as_widget().mouse_interaction(
+                &tree.children[0],
+                base_layout,
+                cursor,
+                viewport,
+                renderer,
+            );
+        };
+
+        Self::dialog(request).as_widget().mouse_interaction(
+            &tree.children[1],
+            dialog_layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let mut layouts = layout.children();
+        let base_layout = layouts.next().unwrap();
+        let dialog = state.requests.front().zip(layouts.next());
+
+        self.base.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            base_layout,
+            if dialog.is_some() {
+                mouse::Cursor::Unavailable
+            } else {
+                cursor
+            },
+            viewport,
+        );
+
+        if let Some((request, dialog_layout)) = dialog {
+            Self::dialog(request).as_widget().draw(
+                &tree.children[1],
+                renderer,
+                theme,
+                style,
+                dialog_layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: core::Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_ref::<State>();
+
+        if !state.requests.is_empty() {
+            return None;
+        }
+
+        self.base.as_widget_mut().overlay(
+            &mut tree.children[0],
+            layout.children().next().unwrap(),
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Host<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog,
+    Message: 'a,
+{
+    fn from(host: Host<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(host)
+    }
+}
@@ -0,0 +1,284 @@
+//! Show the progress of some work to the user.
+use iced_widget::{
+    Column, progress_bar, text,
+    text::{Fragment, IntoFragment},
+};
+
+use crate::core::{
+    self, Border, Color, Element, Event, Layout, Length, Rectangle, Shell,
+    Size, layout, mouse, renderer,
+    time::{Duration, Instant},
+    widget::{Tree, Widget, tree},
+    window,
+};
+use crate::dialog::{Catalog, DEFAULT_MAX_WIDTH, Dialog};
+
+/// The girth of the progress bar of a [`Progress`].
+const BAR_GIRTH: f32 = 10.0;
+
+/// How long the bar of an indeterminate [`Progress`] takes to cross it.
+const PERIOD: Duration = Duration::from_millis(1500);
+
+/// The share of an indeterminate [`Progress`] covered by its bar.
+const BAR_SHARE: f32 = 0.3;
+
+/// A [`Dialog`] showing the progress of some work.
+///
+/// A [`Progress`] does not track the work itself: it shows the given value
+/// and status, so it can be driven by any messages, like the ones produced
+/// by a [`Task`] stream. Without a value, an indeterminate bar is animated
+/// instead.
+///
+/// When a cancel message is set, the cancel button produces it. Unless the
+/// [`Progress`] is not [dismissible], the backdrop and the Escape key
+/// produce it as well.
+///
+/// [`Task`]: https://docs.iced.rs/iced/struct.Task.html
+/// [dismissible]: Progress::dismissible
+pub struct Progress<
+    'a,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> {
+    is_open: bool,
+    base: Element<'a, Message, Theme, Renderer>,
+    title: Fragment<'a>,
+    value: Option<f32>,
+    status: Option<Fragment<'a>>,
+    on_cancel: Option<Message>,
+    cancel_label: &'a str,
+    is_dismissible: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Progress<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Progress`] with the given base, title and value.
+    ///
+    /// The value goes from `0.0` to `1.0`. When it is `None`, the progress
+    /// is indeterminate.
+    pub fn new(
+        is_open: bool,
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        title: impl IntoFragment<'a>,
+        value: Option<f32>,
+    ) -> Self {
+        Self {
+            is_open,
+            base: base.into(),
+            title: title.into_fragment(),
+            value,
+            status: None,
+            on_cancel: None,
+            cancel_label: "Cancel",
+            is_dismissible: true,
+        }
+    }
+
+    /// Sets the status line shown under the progress bar.
+    pub fn status(mut self, status: impl IntoFragment<'a>) -> Self {
+        self.status = Some(status.into_fragment());
+        self
+    }
+
+    /// Sets the message produced when the user cancels the work, which adds
+    /// a cancel button to the [`Progress`].
+    pub fn on_cancel(mut self, on_cancel: Message) -> Self {
+        self.on_cancel = Some(on_cancel);
+        self
+    }
+
+    /// Sets the message produced when the user cancels the work, if
+    /// `Some`.
+    pub fn on_cancel_maybe(mut self, on_cancel: Option<Message>) -> Self {
+        self.on_cancel = on_cancel;
+        self
+    }
+
+    /// Sets the label of the cancel button.
+    ///
+    /// It is "Cancel" by default.
+    pub fn cancel_label(mut self, label: &'a str) -> Self {
+        self.cancel_label = label;
+        self
+    }
+
+    /// Sets whether the backdrop and the Escape key cancel the work.
+    ///
+    /// It is `true` by default. The cancel button is kept either way.
+    pub fn dismissible(mut self, is_dismissible: bool) -> Self {
+        self.is_dismissible = is_dismissible;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Progress<'a, Message, Theme, Renderer>>
+    for Dialog<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog + progress_bar::Catalog,
+    Message: 'a + Clone,
+{
+    fn from(progress: Progress<'a, Message, Theme, Renderer>) -> Self {
+        let bar: Element<'a, Message, Theme, Renderer> = match progress.value {
+            Some(value) => {
+                progress_bar(0.0..=1.0, value).girth(BAR_GIRTH).into()
+            }
+            None => Element::new(Indeterminate {
+                class: <Theme as progress_bar::Catalog>::default(),
+            }),
+        };
+
+        let status = progress.status.map(|status| text(status).into());
+
+        let content = Column::new()
+            .spacing(8)
+            .width(Length::Fill)
+            .push(bar)
+            .extend(status);
+
+        let on_dismiss = progress
+            .on_cancel
+            .clone()
+            .filter(|_| progress.is_dismissible);
+
+        Dialog::new(progress.is_open, progress.base, content)
+            .title(progress.title)
+            .max_width(DEFAULT_MAX_WIDTH)
+            .push_button_maybe(progress.on_cancel.map(|on_cancel| {
+                crate::button(progress.cancel_label, on_cancel)
+            }))
+            .on_press_maybe(on_dismiss.clone())
+            .on_escape_maybe(on_dismiss)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Progress<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog + progress_bar::Catalog,
+    Message: 'a + Clone,
+{
+    fn from(progress: Progress<'a, Message, Theme, Renderer>) -> Self {
+        Dialog::from(progress).into()
+    }
+}
+
+/// A progress bar whose bar keeps crossing it, for work of unknown length.
+struct Indeterminate<'a, Theme>
+where
+    Theme: progress_bar::Catalog,
+{
+    class: Theme::Class<'a>,
+}
+
+/// The state of an [`Indeterminate`] progress bar.
+struct State {
+    start: Option<Instant>,
+    now: Instant,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Indeterminate<'_, Theme>
+where
+    Theme: progress_bar::Catalog,
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State {
+            start: None,
+            now: Instant::now(),
+        })
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fixed(BAR_GIRTH))
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, Length::Fill, BAR_GIRTH)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn core::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let state = tree.state.downcast_mut::<State>();
+
+            let _ = state.start.get_or_insert(*now);
+            state.now = *now;
+
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+        let bounds = layout.bounds();
+        let style = theme.style(&self.class);
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let elapsed = state
+            .start
+            .map(|start| state.now.saturating_duration_since(start))
+            .unwrap_or_default();
+        let cycle = (elapsed.as_secs_f32() / PERIOD.as_secs_f32()).fract();
+
+        let width = bounds.width * BAR_SHARE;
+        let start = bounds.x - width + (bounds.width + width) * cycle;
+        let end = (start + width).min(bounds.x + bounds.width);
+        let start = start.max(bounds.x);
+
+        if end > start {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: start,
+                        width: end - start,
+                        ..bounds
+                    },
+                    border: Border {
+                        color: Color::TRANSPARENT,
+                        ..style.border
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.bar,
+            );
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Ask the user to enter some text.
+use iced_widget::{
+    Column, text,
+    text::{Fragment, IntoFragment},
+    text_input,
+};
+
+use crate::core::{self, Element, Length};
+use crate::dialog::{Catalog, Dialog};
+
+/// A [`Dialog`] asking the user to enter some text.
+///
+/// Like any [`text_input`], a [`Prompt`] does not keep its value: it shows the
+/// given value and produces a message whenever the user edits it.
+///
+/// When a [validator] rejects the value, its error is shown under the input
+/// and the value cannot be submitted. Otherwise, the confirm button and the
+/// Enter key produce the submit message. The cancel button, the backdrop and
+/// the Escape key produce the cancel message.
+///
+/// [`text_input`]: https://docs.iced.rs/iced/widget/text_input/index.html
+/// [validator]: Prompt::validate
+pub struct Prompt<
+    'a,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> {
+    is_open: bool,
+    base: Element<'a, Message, Theme, Renderer>,
+    title: Fragment<'a>,
+    value: &'a str,
+    placeholder: &'a str,
+    is_secure: bool,
+    validator: Option<Box<dyn Fn(&str) -> Result<(), String> + 'a>>,
+    on_input: Box<dyn Fn(String) -> Message + 'a>,
+    on_submit: Message,
+    on_cancel: Message,
+    confirm_label: &'a str,
+    cancel_label: &'a str,
+}
+
+impl<'a, Message, Theme, Renderer> Prompt<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Prompt`] with the given base, title and value.
+    pub fn new(
+        is_open: bool,
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        title: impl IntoFragment<'a>,
+        value: &'a str,
+        on_input: impl Fn(String) -> Message + 'a,
+        on_submit: Message,
+        on_cancel: Message,
+    ) -> Self {
+        Self {
+            is_open,
+            base: base.into(),
+            title: title.into_fragment(),
+            value,
+            placeholder: "",
+            is_secure: false,
+            validator: None,
+            on_input: Box::new(on_input),
+            on_submit,
+            on_cancel,
+            confirm_label: "Ok",
+            cancel_label: "Cancel",
+        }
+    }
+
+    /// Sets the placeholder of the input.
+    pub fn placeholder(mut self, placeholder: &'a str) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Sets whether the input hides its value, like a password field.
+    pub fn secure(mut self, is_secure: bool) -> Self {
+        self.is_secure = is_secure;
+        self
+    }
+
+    /// Sets the validator of the value.
+    ///
+    /// The validator returns the error to show under the input when the
+    /// value cannot be submitted.
+    pub fn validate(
+        mut self,
+        validator: impl Fn(&str) -> Result<(), String> + 'a,
+    ) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Sets the label of the confirm button.
+    ///
+    /// It is "Ok" by default.
+    pub fn confirm_label(mut self, label: &'a str) -> Self {
+        self.confirm_label = label;
+        self
+    }
+
+    /// Sets the label of the cancel button.
+    ///
+    /// It is "Cancel" by default.
+    pub fn cancel_label(mut self, label: &'a str) -> Self {
+        self.cancel_label = label;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Prompt<'a, Message, Theme, Renderer>>
+    for Dialog<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog + text_input::Catalog,
+    Message: 'a + Clone,
+{
+    fn from(prompt: Prompt<'a, Message, Theme, Renderer>) -> Self {
+        let error = prompt
+            .validator
+            .and_then(|validator| validator(prompt.value).err());
+        let is_valid = error.is_none();
+
+        let input = text_input(prompt.placeholder, prompt.value)
+            .on_input(prompt.on_input)
+            .on_submit_maybe(is_valid.then(|| prompt.on_submit.clone()))
+            .secure(prompt.is_secure)
+            .width(Length::Fill);
+
+        let error = error.map(|error| {
+            text(error)
+                .size(14)
+                .class(<Theme as Catalog>::default_validation())
+                .into()
+        });
+
+        let content = Column::new().spacing(8).push(input).extend(error);
+
+        let dialog = Dialog::new(prompt.is_open, prompt.base, content)
+            .title(prompt.title)
+            .push_button(crate::button(
+                prompt.cancel_label,
+                prompt.on_cancel.clone(),
+            ))
+            .on_press(prompt.on_cancel.clone())
+            .on_escape(prompt.on_cancel);
+
+        if is_valid {
+            dialog.push_default_button(prompt.confirm_label, prompt.on_submit)
+        } else {
+            dialog.push_button(
+                crate::button(prompt.confirm_label, prompt.on_submit)
+                    .on_press_maybe(None)
+                    .class(<Theme as Catalog>::default_accent_button()),
+            )
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Prompt<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog + text_input::Catalog,
+    Message: 'a + Clone,
+{
+    fn from(prompt: Prompt<'a, Message, Theme, Renderer>) -> Self {
+        Dialog::from(prompt).into()
+    }
+}
@@ -0,0 +1,227 @@
+//! Show pending dialogs one at a time.
+use std::collections::VecDeque;
+
+use iced_widget::text;
+
+use crate::core::widget::Id;
+use crate::core::{self, Element};
+use crate::dialog::{Catalog, Dialog};
+
+/// A queue of pending dialogs.
+///
+/// Keep a [`Queue`] in your application state, [`push`] dialogs to it from
+/// anywhere in your update logic and show the one at the front with
+/// [`view`]. Once the user has acted on it, [`dismiss`] it to show the next
+/// one.
+///
+/// [`push`]: Queue::push
+/// [`view`]: Queue::view
+/// [`dismiss`]: Queue::dismiss
+#[derive(Debug, Clone)]
+pub struct Queue<Message> {
+    pending: VecDeque<Entry<Message>>,
+}
+
+impl<Message> Queue<Message> {
+    /// Creates an empty [`Queue`].
+    pub fn new() -> Self {
+        Self {
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Adds an [`Entry`] to the back of the [`Queue`].
+    pub fn push(&mut self, entry: Entry<Message>) {
+        self.pending.push_back(entry);
+    }
+
+    /// Removes the [`Entry`] at the front of the [`Queue`], showing the next
+    /// one, and returns it.
+    pub fn dismiss(&mut self) -> Option<Entry<Message>> {
+        self.pending.pop_front()
+    }
+
+    /// Returns the [`Entry`] at the front of the [`Queue`], if any.
+    pub fn front(&self) -> Option<&Entry<Message>> {
+        self.pending.front()
+    }
+
+    /// Returns the number of pending entries.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns whether the [`Queue`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Removes every pending [`Entry`].
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Shows the [`Entry`] at the front of the [`Queue`] over the given base.
+    ///
+    /// The returned [`Dialog`] is closed while the [`Queue`] is empty, and it
+    /// can be configured further like any other [`Dialog`]. Each [`Entry`]
+    /// gives it a different [`Id`], so the focus moves to the next [`Entry`]
+    /// once the current one is dismissed.
+    pub fn view<'a, Theme, Renderer>(
+        &'a self,
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Dialog<'a, Message, Theme, Renderer>
+    where
+        Renderer: 'a + core::Renderer + core::text::Renderer,
+        Theme: 'a + Catalog,
+        Message: 'a + Clone,
+    {
+        match self.front() {
+            Some(entry) => entry.view(base),
+            None => Dialog::new(false, base, text("")),
+        }
+    }
+}
+
+impl<Message> Default for Queue<Message> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A dialog waiting in a [`Queue`].
+#[derive(Debug, Clone)]
+pub struct Entry<Message> {
+    id: Id,
+    title: Option<String>,
+    content: String,
+    buttons: Vec<Button<Message>>,
+    on_escape: Option<Message>,
+    on_press: Option<Message>,
+}
+
+/// A button of an [`Entry`].
+#[derive(Debug, Clone)]
+struct Button<Message> {
+    label: String,
+    on_press: Message,
+    is_default: bool,
+}
+
+impl<Message> Entry<Message> {
+    /// Creates a new [`Entry`] with the given content.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            id: Id::unique(),
+            title: None,
+            content: content.into(),
+            buttons: Vec::new(),
+            on_escape: None,
+            on_press: None,
+        }
+    }
+
+    /// Sets the title of the [`Entry`].
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Adds a button to the [`Entry`].
+    pub fn button(
+        mut self,
+        label: impl Into<String>,
+        on_press: Message,
+    ) -> Self {
+        self.buttons.push(Button {
+            label: label.into(),
+            on_press,
+            is_default: false,
+        });
+        self
+    }
+
+    /// Adds the default button to the [`Entry`].
+    ///
+    /// See [`Dialog::push_default_button`] for details.
+    pub fn default_button(
+        mut self,
+        label: impl Into<String>,
+        on_press: Message,
+    ) -> Self {
+        self.buttons.push(Button {
+            label: label.into(),
+            on_press,
+            is_default: true,
+        });
+        self
+    }
+
+    /// Sets the message that will be produced when the Escape key is pressed
+    /// while the [`Entry`] is shown.
+    pub fn on_escape(mut self, on_escape: Message) -> Self {
+        self.on_escape = Some(on_escape);
+        self
+    }
+
+    /// Sets the message that will be produced when the backdrop is pressed
+    /// while the [`Entry`] is shown.
+    pub fn on_press(mut self, on_press: Message) -> Self {
+        self.on_press = Some(on_press);
+        self
+    }
+
+    /// Applies a transformation to the messages produced by the [`Entry`].
+    pub fn map<B>(self, mut f: impl FnMut(Message) -> B) -> Entry<B> {
+        Entry {
+            id: self.id,
+            title: self.title,
+            content: self.content,
+            buttons: self
+                .buttons
+                .into_iter()
+                .map(|button| Button {
+                    label: button.label,
+                    on_press: f(button.on_press),
+                    is_default: button.is_default,
+                })
+                .collect(),
+            on_escape: self.on_escape.map(&mut f),
+            on_press: self.on_press.map(f),
+        }
+    }
+
+    /// Shows the [`Entry`] in an open [`Dialog`] over the given base.
+    pub(crate) fn view<'a, Theme, Renderer>(
+        &'a self,
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Dialog<'a, Message, Theme, Renderer>
+    where
+        Renderer: 'a + core::Renderer + core::text::Renderer,
+        Theme: 'a + Catalog,
+        Message: 'a + Clone,
+    {
+        let dialog = Dialog::new(true, base, text(self.content.as_str()))
+            .id(self.id.clone())
+            .on_escape_maybe(self.on_escape.clone())
+            .on_press_maybe(self.on_press.clone());
+
+        let dialog = if let Some(title) = &self.title {
+            dialog.title(title.as_str())
+        } else {
+            dialog
+        };
+
+        self.buttons.iter().fold(dialog, |dialog, button| {
+            if button.is_default {
+                dialog
+                    .push_default_button(&button.label, button.on_press.clone())
+            } else {
+                dialog.push_button(crate::button(
+                    &button.label,
+                    button.on_press.clone(),
+                ))
+            }
+        })
+    }
+}
@@ -0,0 +1,444 @@
+//! Guide the user through a sequence of pages.
+use std::fmt;
+
+use iced_widget::text::{Fragment, IntoFragment};
+
+use crate::core::{
+    self, Element, Event, Layout, Length, Rectangle, Shell, Size, Vector,
+    layout, mouse, overlay, renderer,
+    widget::{Operation, Tree, Widget},
+};
+use crate::dialog::{Catalog, Dialog};
+
+/// A [`Dialog`] guiding the user through a sequence of [`Page`]s.
+///
+/// A [`Wizard`] shows the current page under a title ending with the
+/// [`Step`] it is at, and Cancel, Back and Next buttons, the latter becoming
+/// Finish on the last page. Next and Finish are disabled while the current
+/// page [cannot proceed]. A [`Wizard`] without pages only shows its title,
+/// and cannot be finished.
+///
+/// The current page is up to the application: a [`Wizard`] shows the given
+/// page and produces a message with the index of the page to show whenever
+/// the user navigates. Every page stays in the widget tree while hidden, so
+/// their widget state survives going back and forth.
+///
+/// The cancel button, the backdrop and the Escape key produce the cancel
+/// message.
+///
+/// [cannot proceed]: Page::can_proceed
+pub struct Wizard<
+    'a,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> {
+    is_open: bool,
+    base: Element<'a, Message, Theme, Renderer>,
+    title: Fragment<'a>,
+    title_with: Option<Box<dyn Fn(Step<'_>) -> String + 'a>>,
+    pages: Vec<Page<'a, Message, Theme, Renderer>>,
+    current: usize,
+    on_navigate: Box<dyn Fn(usize) -> Message + 'a>,
+    on_finish: Message,
+    on_cancel: Message,
+    back_label: &'a str,
+    next_label: &'a str,
+    finish_label: &'a str,
+    cancel_label: &'a str,
+}
+
+impl<'a, Message, Theme, Renderer> Wizard<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Wizard`] with the given base and title, showing the
+    /// page at the given index.
+    ///
+    /// `on_navigate` is called with the index of the page to show when the
+    /// user goes back or forth.
+    pub fn new(
+        is_open: bool,
+        base: impl Into<Element<'a, Message, Theme, Renderer>>,
+        title: impl IntoFragment<'a>,
+        current: usize,
+        on_navigate: impl Fn(usize) -> Message + 'a,
+        on_finish: Message,
+        on_cancel: Message,
+    ) -> Self {
+        Self {
+            is_open,
+            base: base.into(),
+            title: title.into_fragment(),
+            title_with: None,
+            pages: Vec::new(),
+            current,
+            on_navigate: Box::new(on_navigate),
+            on_finish,
+            on_cancel,
+            back_label: "Back",
+            next_label: "Next",
+            finish_label: "Finish",
+            cancel_label: "Cancel",
+        }
+    }
+
+    /// Sets the function producing the title of the [`Wizard`] from the
+    /// [`Step`] it is at, in place of the given title followed by the
+    /// [`Step`].
+    pub fn title_with(
+        mut self,
+        title: impl Fn(Step<'_>) -> String + 'a,
+    ) -> Self {
+        self.title_with = Some(Box::new(title));
+        self
+    }
+
+    /// Adds a [`Page`] to the [`Wizard`].
+    pub fn push(mut self, page: Page<'a, Message, Theme, Renderer>) -> Self {
+        self.pages.push(page);
+        self
+    }
+
+    /// Extends the [`Wizard`] with the given [`Page`]s.
+    pub fn extend(
+        self,
+        pages: impl IntoIterator<Item = Page<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        pages.into_iter().fold(self, Self::push)
+    }
+
+    /// Sets the label of the back button.
+    ///
+    /// It is "Back" by default.
+    pub fn back_label(mut self, label: &'a str) -> Self {
+        self.back_label = label;
+        self
+    }
+
+    /// Sets the label of the next button.
+    ///
+    /// It is "Next" by default.
+    pub fn next_label(mut self, label: &'a str) -> Self {
+        self.next_label = label;
+        self
+    }
+
+    /// Sets the label of the finish button, shown on the last page.
+    ///
+    /// It is "Finish" by default.
+    pub fn finish_label(mut self, label: &'a str) -> Self {
+        self.finish_label = label;
+        self
+    }
+
+    /// Sets the label of the cancel button.
+    ///
+    /// It is "Cancel" by default.
+    pub fn cancel_label(mut self, label: &'a str) -> Self {
+        self.cancel_label = label;
+        self
+    }
+}
+
+/// The step a [`Wizard`] is at.
+///
+/// It is displayed as "Step 2 of 3: Format".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step<'a> {
+    /// The number of the step, starting at 1.
+    pub number: usize,
+    /// The number of steps.
+    pub total: usize,
+    /// The title of the current [`Page`].
+    pub title: &'a str,
+}
+
+impl fmt::Display for Step<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Step {} of {}: {}", self.number, self.total, self.title)
+    }
+}
+
+/// A page of a [`Wizard`].
+pub struct Page<
+    'a,
+    Message,
+    Theme = iced_widget::Theme,
+    Renderer = iced_widget::Renderer,
+> {
+    title: Fragment<'a>,
+    content: Element<'a, Message, Theme, Renderer>,
+    can_proceed: bool,
+}
+
+impl<'a, Message, Theme, Renderer> Page<'a, Message, Theme, Renderer> {
+    /// Creates a new [`Page`] with the given title and content.
+    pub fn new(
+        title: impl IntoFragment<'a>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            title: title.into_fragment(),
+            content: content.into(),
+            can_proceed: true,
+        }
+    }
+
+    /// Sets whether the user can proceed past the [`Page`].
+    ///
+    /// It is `true` by default.
+    pub fn can_proceed(mut self, can_proceed: bool) -> Self {
+        self.can_proceed = can_proceed;
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Wizard<'a, Message, Theme, Renderer>>
+    for Dialog<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog,
+    Message: 'a + Clone,
+{
+    fn from(wizard: Wizard<'a, Message, Theme, Renderer>) -> Self {
+        let total = wizard.pages.len();
+        let current = wizard.current.min(total.saturating_sub(1));
+        let is_last = current + 1 >= total;
+
+        let (titles, pages): (Vec<_>, Vec<_>) = wizard
+            .pages
+            .into_iter()
+            .map(|page| ((page.title, page.can_proceed), page.content))
+            .unzip();
+
+        let (title, can_proceed) = match titles.into_iter().nth(current) {
+            Some((page, can_proceed)) => {
+                let step = Step {
+                    number: current + 1,
+                    total,
+                    title: &page,
+                };
+
+                let title = match &wizard.title_with {
+                    Some(title_with) => title_with(step),
+                    None => format!("{} \u{2014} {step}", wizard.title),
+                };
+
+                (title, can_proceed)
+            }
+            // A wizard without pages has no step to show, nor to finish
+            None => (wizard.title.into_owned(), false),
+        };
+
+        let back = crate::label_button(wizard.back_label).on_press_maybe(
+            (current > 0).then(|| (wizard.on_navigate)(current - 1)),
+        );
+
+        let (label, forward) = if is_last {
+            (wizard.finish_label, wizard.on_finish)
+        } else {
+            (wizard.next_label, (wizard.on_navigate)(current + 1))
+        };
+
+        let dialog =
+            Dialog::new(wizard.is_open, wizard.base, Pages { pages, current })
+                .title(title)
+                .push_button(crate::button(
+                    wizard.cancel_label,
+                    wizard.on_cancel.clone(),
+                ))
+                .push_button(back)
+                .on_press(wizard.on_cancel.clone())
+                .on_escape(wizard.on_cancel);
+
+        if can_proceed {
+            dialog.push_default_button(label, forward)
+        } else {
+            dialog.push_button(
+                crate::button(label, forward)
+                    .on_press_maybe(None)
+                    .class(<Theme as Catalog>::default_accent_button()),
+            )
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Wizard<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + Catalog,
+    Message: 'a + Clone,
+{
+    fn from(wizard: Wizard<'a, Message, Theme, Renderer>) -> Self {
+        Dialog::from(wizard).into()
+    }
+}
+
+/// The pages of a [`Wizard`], of which only the current one is shown.
+///
+/// Every page keeps its own widget tree, so hidden pages keep their state.
+struct Pages<'a, Message, Theme, Renderer> {
+    pages: Vec<Element<'a, Message, Theme, Renderer>>,
+    current: usize,
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Pages<'_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.pages.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.pages);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.pages
+            .get(self.current)
+            .map_or(Size::new(Length::Shrink, Length::Shrink), |page| {
+                page.as_widget().size()
+            })
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.pages
+            .get(self.current)
+            .map_or(Size::new(Length::Shrink, Length::Shrink), |page| {
+                page.as_widget().size_hint()
+            })
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        match self.pages.get_mut(self.current) {
+            Some(page) => page.as_widget_mut().layout(
+                &mut tree.children[self.current],
+                renderer,
+                limits,
+            ),
+            None => layout::Node::new(Size::ZERO),
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        if let Some(page) = self.pages.get_mut(self.current) {
+            page.as_widget_mut().operate(
+                &mut tree.children[self.current],
+                layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn core::Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        if let Some(page) = self.pages.get_mut(self.current) {
+            page.as_widget_mut().update(
+                &mut tree.children[self.current],
+                event,
+                layout,
+                cursor,
+                renderer,
+                clipboard,
+                shell,
+                viewport,
+            );
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        if let Some(page) = self.pages.get(self.current) {
+            page.as_widget().draw(
+                &tree.children[self.current],
+                renderer,
+                theme,
+                style,
+                layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.pages.get(self.current).map_or(
+            mouse::Interaction::default(),
+            |page| {
+                page.as_widget().mouse_interaction(
+                    &tree.children[self.current],
+                    layout,
+                    cursor,
+                    viewport,
+                    renderer,
+                )
+            },
+        )
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        self.pages.get_mut(self.current)?.as_widget_mut().overlay(
+            &mut tree.children[self.current],
+            layout,
+            renderer,
+            viewport,
+            translation,
+        )
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Pages<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer,
+    Theme: 'a,
+    Message: 'a,
+{
+    fn from(pages: Pages<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(pages)
+    }
+}
@@ -1,9 +1,10 @@
 #![doc = include_str!("../README.md")]
 pub mod dialog;
 pub use dialog::Dialog;
+pub use dialog::host::{ask, confirm};
 use iced_widget::Button;
 use iced_widget::core;
-use iced_widget::{container, text};
+use iced_widget::text;
 
 /// Creates a new [`Dialog`] with the given base and dialog content.
 pub fn dialog<'a, Message, Theme, Renderer>(
@@ -15,21 +16,48 @@ where
     Renderer: 'a + core::Renderer + core::text::Renderer,
     Theme: 'a + dialog::Catalog,
     Message: 'a + Clone,
-    <Theme as container::Catalog>::Class<'a>:
-        From<container::StyleFn<'a, Theme>>,
 {
     Dialog::new(is_open, base, content)
 }
 
+/// Creates a new [`Host`] with the given base.
+///
+/// [`Host`]: dialog::Host
+pub fn host<'a, Message, Theme, Renderer>(
+    base: impl Into<core::Element<'a, Message, Theme, Renderer>>,
+) -> dialog::Host<'a, Message, Theme, Renderer>
+where
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+    Theme: 'a + dialog::Catalog,
+{
+    dialog::Host::new(base)
+}
+
 /// Pre-styled [`Button`] for [`Dialog`]s.
 ///
+/// The [`Button`] has the [`default_button`] class of the [`Catalog`], which
+/// sets it apart from the default button of a [`Dialog`].
+///
 /// [`Button`]: https://docs.iced.rs/iced/widget/struct.Button.html
+/// [`default_button`]: dialog::Catalog::default_button
+/// [`Catalog`]: dialog::Catalog
 pub fn button<'a, Message, Theme, Renderer>(
     content: &'a str,
     message: Message,
 ) -> Button<'a, Message, Theme, Renderer>
 where
-    Theme: 'a + iced_widget::button::Catalog + text::Catalog,
+    Theme: 'a + dialog::Catalog,
+    Renderer: 'a + core::Renderer + core::text::Renderer,
+{
+    label_button(content).on_press(message)
+}
+
+/// Pre-styled [`Button`] for [`Dialog`]s, without a message.
+fn label_button<'a, Message, Theme, Renderer>(
+    content: &'a str,
+) -> Button<'a, Message, Theme, Renderer>
+where
+    Theme: 'a + dialog::Catalog,
     Renderer: 'a + core::Renderer + core::text::Renderer,
 {
     iced_widget::button(
@@ -38,7 +66,7 @@ where
             .line_height(text::LineHeight::Absolute(core::Pixels(20.0)))
             .align_x(core::Alignment::Center),
     )
-    .on_press(message)
     .height(32)
     .width(core::Length::Fill)
+    .class(<Theme as dialog::Catalog>::default_button())
 }
@@ -0,0 +1,51 @@
+#![allow(missing_docs)]
+use iced::Element;
+use iced::keyboard::key::Named;
+use iced_dialog::Dialog;
+use iced_dialog::dialog::{Alert, Severity};
+use iced_test::{Error, selector, simulator};
+use iced_widget::{center, text};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Acknowledged,
+}
+
+fn alert() -> Element<'static, Message> {
+    Dialog::from(Alert::new(
+        true,
+        center(text("Base")),
+        Severity::Warning,
+        "Disk almost full",
+        "Only 1% of the disk space is left.",
+        Message::Acknowledged,
+    ))
+    .id("alert")
+    .into()
+}
+
+#[test]
+fn escape_acknowledges() {
+    let mut ui = simulator(alert());
+    let _ = ui.tap_key(Named::Escape);
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Acknowledged]
+    );
+}
+
+#[test]
+fn title_leaves_room_for_icon() -> Result<(), Error> {
+    let mut ui = simulator(alert());
+
+    let dialog = ui.find(selector::id("alert"))?.bounds();
+    let title = ui.find("Disk almost full")?.bounds();
+
+    assert!(
+        title.x - dialog.x >= 24.0 + 26.0,
+        "icon should precede title"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+#![allow(missing_docs)]
+use iced::Element;
+use iced::keyboard::key::Named;
+use iced_dialog::dialog::Choice;
+use iced_dialog::dialog::choice::Item;
+use iced_test::simulator;
+use iced_widget::{center, text};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Message {
+    Selected(u8),
+    Toggled(u8, bool),
+    Picked(Option<u8>),
+    PickedMany(Option<Vec<u8>>),
+}
+
+fn items() -> Vec<Item<'static, u8>> {
+    vec![
+        Item::new(1, "One").description("The first option"),
+        Item::new(2, "Two"),
+        Item::new(3, "Three").description("The last option"),
+    ]
+}
+
+fn single(selected: Option<u8>) -> Element<'static, Message> {
+    Choice::single(
+        true,
+        center(text("Base")),
+        "Pick one",
+        items(),
+        selected,
+        Message::Selected,
+        Message::Picked,
+    )
+    .into()
+}
+
+fn multiple(selected: &[u8]) -> Element<'static, Message> {
+    Choice::multiple(
+        true,
+        center(text("Base")),
+        "Pick some",
+        items(),
+        selected.to_vec(),
+        Message::Toggled,
+        Message::PickedMany,
+    )
+    .into()
+}
+
+fn press(choice: Element<'_, Message>, key: Named) -> Vec<Message> {
+    let mut ui = simulator(choice);
+    let _ = ui.tap_key(key);
+
+    ui.into_messages().collect()
+}
+
+#[test]
+fn single_choice_needs_a_selection() {
+    use Named::{Enter, Escape};
+
+    assert_eq!(press(single(Some(2)), Enter), [Message::Picked(Some(2))]);
+    assert_eq!(press(single(None), Enter), []);
+    assert_eq!(press(single(Some(2)), Escape), [Message::Picked(None)]);
+}
+
+#[test]
+fn multiple_choice_keeps_the_order_of_the_options() {
+    use Named::{Enter, Escape};
+
+    assert_eq!(
+        press(multiple(&[3, 1]), Enter),
+        [Message::PickedMany(Some(vec![1, 3]))]
+    );
+    assert_eq!(
+        press(multiple(&[]), Enter),
+        [Message::PickedMany(Some(vec![]))]
+    );
+    assert_eq!(press(multiple(&[1]), Escape), [Message::PickedMany(None)]);
+}
@@ -0,0 +1,89 @@
+#![allow(missing_docs)]
+use iced::Element;
+use iced::keyboard::key::Named;
+use iced::{Event, Point, Rectangle, mouse};
+use iced_dialog::Dialog;
+use iced_test::{Error, selector, simulator};
+use iced_widget::{center, text};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Closed,
+}
+
+const TITLE: &str = "A rather long title for a dialog";
+
+fn dialog(is_closable: bool) -> Element<'static, Message> {
+    Dialog::new(true, center(text("Base")), text("Content"))
+        .id("dialog")
+        .title(TITLE)
+        .on_close(Message::Closed)
+        .closable(is_closable)
+        .into()
+}
+
+/// Returns the center of the close button of a [`Dialog`] with the given
+/// bounds and the default padding.
+fn close_button(dialog: Rectangle) -> Point {
+    Point::new(
+        dialog.x + dialog.width - 24.0 - 13.0,
+        dialog.y + 24.0 + 13.0,
+    )
+}
+
+/// Presses the mouse over the close button of the [`Dialog`] and releases
+/// it at the position returned by `release`, returning the produced
+/// messages.
+fn click(
+    dialog: Element<'_, Message>,
+    release: impl Fn(Point) -> Point,
+) -> Result<Vec<Message>, Error> {
+    let mut ui = simulator(dialog);
+    let close = close_button(ui.find(selector::id("dialog"))?.bounds());
+
+    ui.point_at(close);
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+        mouse::Button::Left,
+    ))]);
+
+    ui.point_at(release(close));
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonReleased(
+        mouse::Button::Left,
+    ))]);
+
+    Ok(ui.into_messages().collect())
+}
+
+#[test]
+fn close_button_produces_message() -> Result<(), Error> {
+    assert_eq!(click(dialog(true), |_| Point::ORIGIN)?, []);
+    assert_eq!(click(dialog(true), |close| close)?, [Message::Closed]);
+
+    let mut ui = simulator(dialog(true));
+    let close = close_button(ui.find(selector::id("dialog"))?.bounds());
+    let title = ui.find(TITLE)?.bounds();
+
+    assert!(title.x + title.width <= close.x - 13.0);
+
+    Ok(())
+}
+
+#[test]
+fn close_button_can_be_hidden() -> Result<(), Error> {
+    assert_eq!(click(dialog(false), |close| close)?, []);
+
+    Ok(())
+}
+
+#[test]
+fn escape_closes_closable_dialogs() {
+    let escape = |is_closable| {
+        let mut ui = simulator(dialog(is_closable));
+        let _ = ui.tap_key(Named::Escape);
+
+        ui.into_messages().collect::<Vec<_>>()
+    };
+
+    assert_eq!(escape(true), [Message::Closed]);
+    assert_eq!(escape(false), []);
+}
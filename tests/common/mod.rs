@@ -0,0 +1,165 @@
+//! The harness shared by the integration tests.
+//!
+//! Most tests run a single view through [`iced_test::simulator`]. A
+//! [`Session`] is used instead when the view has to be rebuilt with the
+//! widget state left by the previous one, like in a running application,
+//! or when the tests need more than a [`Simulator`] tells.
+//!
+//! The helpers that only some tests need live next to this module, in
+//! `widgets.rs` and `screenshot.rs`, and are declared by the tests using
+//! them. The few reading what a [`Ui`] reports after an update stay here.
+//!
+//! [`Simulator`]: iced_test::Simulator
+use iced::Element;
+use iced_test::core::renderer::Headless;
+use iced_test::core::time::Instant;
+use iced_test::core::{
+    Clipboard, Event, Font, Pixels, Size, clipboard, mouse, window,
+};
+use iced_test::futures::futures::executor;
+use iced_test::runtime::UserInterface;
+use iced_test::runtime::user_interface::{self, Cache};
+
+/// The renderer the tests draw with.
+pub type Renderer = iced::Renderer;
+
+/// The size of the window of a [`Session`].
+pub const SIZE: Size = Size::new(1024.0, 768.0);
+
+/// A simulated user interface whose widget state survives rebuilding it
+/// with new views.
+pub struct Session<Message> {
+    cache: Option<Cache>,
+    pub renderer: Renderer,
+    pub cursor: mouse::Cursor,
+    /// What was last written to the clipboard, if anything.
+    pub clipboard: Recorder,
+    /// The messages produced so far.
+    pub messages: Vec<Message>,
+}
+
+impl<Message> Session<Message> {
+    /// Creates a new [`Session`] with no widget state.
+    pub fn new() -> Self {
+        let renderer = executor::block_on(Renderer::new(
+            Font::with_name("Fira Sans"),
+            Pixels(16.0),
+            None,
+        ))
+        .expect("Create new headless renderer");
+
+        Self {
+            cache: Some(Cache::default()),
+            renderer,
+            cursor: mouse::Cursor::Unavailable,
+            clipboard: Recorder(None),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Builds the given view with the widget state left by the previous
+    /// ones.
+    pub fn view<'s, 'a>(
+        &'s mut self,
+        element: impl Into<Element<'a, Message>>,
+    ) -> Ui<'s, 'a, Message> {
+        let cache = self.cache.take().unwrap_or_default();
+        let raw =
+            UserInterface::build(element, SIZE, cache, &mut self.renderer);
+
+        Ui {
+            session: self,
+            raw: Some(raw),
+        }
+    }
+}
+
+/// A view built by a [`Session`].
+pub struct Ui<'s, 'a, Message> {
+    pub session: &'s mut Session<Message>,
+    pub raw: Option<UserInterface<'a, Message, iced::Theme, Renderer>>,
+}
+
+impl<Message> Ui<'_, '_, Message> {
+    /// Simulates the given events and returns the resulting state of the
+    /// user interface.
+    ///
+    /// The mouse cursor follows the [`CursorMoved`] events.
+    ///
+    /// [`CursorMoved`]: mouse::Event::CursorMoved
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> user_interface::State {
+        let events: Vec<Event> = events.into_iter().collect();
+        let Ui { session, raw } = self;
+
+        for event in &events {
+            if let Event::Mouse(mouse::Event::CursorMoved { position }) = event
+            {
+                session.cursor = mouse::Cursor::Available(*position);
+            }
+        }
+
+        let (state, _statuses) = raw
+            .as_mut()
+            .expect("user interface should be built")
+            .update(
+                &events,
+                session.cursor,
+                &mut session.renderer,
+                &mut session.clipboard,
+                &mut session.messages,
+            );
+
+        state
+    }
+}
+
+// Not every test binary reads all of what its views report.
+#[allow(dead_code)]
+impl<Message> Ui<'_, '_, Message> {
+    /// Redraws the user interface at the given time and returns when it
+    /// asks to be redrawn next.
+    pub fn redraw(&mut self, now: Instant) -> window::RedrawRequest {
+        match self
+            .simulate([Event::Window(window::Event::RedrawRequested(now))])
+        {
+            user_interface::State::Updated { redraw_request, .. } => {
+                redraw_request
+            }
+            user_interface::State::Outdated => window::RedrawRequest::Wait,
+        }
+    }
+
+    /// Returns the mouse interaction shown at the current cursor position.
+    pub fn mouse_interaction(&mut self) -> mouse::Interaction {
+        match self.simulate([]) {
+            user_interface::State::Updated {
+                mouse_interaction, ..
+            } => mouse_interaction,
+            user_interface::State::Outdated => mouse::Interaction::None,
+        }
+    }
+}
+
+impl<Message> Drop for Ui<'_, '_, Message> {
+    fn drop(&mut self) {
+        if let Some(raw) = self.raw.take() {
+            self.session.cache = Some(raw.into_cache());
+        }
+    }
+}
+
+/// A clipboard remembering what was last written to it.
+pub struct Recorder(pub Option<String>);
+
+impl Clipboard for Recorder {
+    fn read(&self, _kind: clipboard::Kind) -> Option<String> {
+        self.0.clone()
+    }
+
+    fn write(&mut self, _kind: clipboard::Kind, contents: String) {
+        self.0 = Some(contents);
+    }
+}
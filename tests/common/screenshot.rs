@@ -0,0 +1,54 @@
+//! Drawing a [`Ui`] and looking at its pixels.
+use iced_test::core::renderer::{self, Headless};
+use iced_test::core::{Color, Point, Size};
+
+use crate::common::{SIZE, Ui};
+
+impl<Message> Ui<'_, '_, Message> {
+    /// Draws the user interface with the given [`Theme`], as of its last
+    /// redraw, and returns the color of each of its pixels.
+    ///
+    /// [`Theme`]: iced::Theme
+    pub fn screenshot(&mut self, theme: &iced::Theme) -> Screenshot {
+        let Ui { session, raw } = self;
+        let base = iced_test::core::theme::Base::base(theme);
+
+        raw.as_mut().expect("user interface should be built").draw(
+            &mut session.renderer,
+            theme,
+            &renderer::Style {
+                text_color: base.text_color,
+            },
+            session.cursor,
+        );
+
+        let size = Size::new(SIZE.width as u32, SIZE.height as u32);
+
+        Screenshot {
+            rgba: session
+                .renderer
+                .screenshot(size, 1.0, base.background_color),
+            width: size.width,
+        }
+    }
+}
+
+/// The pixels drawn by a [`Ui`].
+pub struct Screenshot {
+    rgba: Vec<u8>,
+    width: u32,
+}
+
+impl Screenshot {
+    /// Returns the color of the pixel at the given position.
+    pub fn pixel(&self, position: Point) -> Color {
+        let index = (position.y as usize * self.width as usize
+            + position.x as usize)
+            * 4;
+        let [r, g, b, a] = self.rgba[index..index + 4] else {
+            unreachable!("a pixel has four channels");
+        };
+
+        Color::from_rgba8(r, g, b, f32::from(a) / 255.0)
+    }
+}
@@ -0,0 +1,43 @@
+//! Finding, operating on and pointing at the widgets of a [`Ui`].
+use iced_test::core::widget::{self, Operation};
+use iced_test::core::{Event, Point, mouse};
+use iced_test::{Error, Selector};
+
+use crate::common::Ui;
+
+impl<Message> Ui<'_, '_, Message> {
+    /// Finds the target of the given [`Selector`].
+    pub fn find<S>(&mut self, selector: S) -> Result<S::Output, Error>
+    where
+        S: Selector + Send,
+        S::Output: Clone + Send,
+    {
+        let description = selector.description();
+        let mut operation = selector.find();
+
+        self.operate(&mut widget::operation::black_box(&mut operation));
+
+        match operation.finish() {
+            widget::operation::Outcome::Some(Some(output)) => Ok(output),
+            _ => Err(Error::SelectorNotFound {
+                selector: description,
+            }),
+        }
+    }
+
+    /// Runs the given [`Operation`] on the user interface.
+    pub fn operate(&mut self, operation: &mut dyn Operation) {
+        let Ui { session, raw } = self;
+
+        raw.as_mut()
+            .expect("user interface should be built")
+            .operate(&session.renderer, operation);
+    }
+
+    /// Moves the mouse cursor to the given position.
+    pub fn move_to(&mut self, position: impl Into<Point>) {
+        let _ = self.simulate([Event::Mouse(mouse::Event::CursorMoved {
+            position: position.into(),
+        })]);
+    }
+}
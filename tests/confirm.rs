@@ -0,0 +1,184 @@
+#![allow(missing_docs)]
+use iced::Element;
+use iced::keyboard::key::Named;
+use iced::{Event, Point, mouse};
+use iced_dialog::Dialog;
+use iced_dialog::dialog::Confirm;
+use iced_test::{Error, selector, simulator};
+use iced_widget::{button, center, text};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Answered(bool),
+    AnsweredAsking(bool, bool),
+    DontAskAgain(bool),
+}
+
+fn confirm() -> Confirm<'static, Message> {
+    Confirm::new(
+        true,
+        center(text("Base")),
+        "Delete",
+        "Delete all your work?",
+        Message::Answered,
+    )
+}
+
+#[test]
+fn keys_answer() {
+    for (key, answer) in [(Named::Enter, true), (Named::Escape, false)] {
+        let mut ui = simulator(confirm().confirm_label("Delete"));
+        let _ = ui.tap_key(key);
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [Message::Answered(answer)]
+        );
+    }
+}
+
+#[test]
+fn backdrop_cancels() {
+    let mut ui = simulator(confirm());
+
+    ui.point_at(Point::new(10.0, 10.0));
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+        mouse::Button::Left,
+    ))]);
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Answered(false)]
+    );
+}
+
+#[test]
+fn checkbox_sits_left_of_the_buttons() -> Result<(), Error> {
+    let confirm: Element<'_, Message> = Dialog::from(confirm().checkbox(
+        "Don't ask again",
+        false,
+        Message::DontAskAgain,
+    ))
+    .id("confirm")
+    .into();
+
+    let mut ui = simulator(confirm);
+
+    let dialog = ui.find(selector::id("confirm"))?.bounds();
+    let checkbox = ui.find("Don't ask again")?.bounds();
+    let cancel = ui.find("Cancel")?.bounds();
+    let ok = ui.find("Ok")?.bounds();
+
+    assert!(checkbox.x < dialog.x + dialog.width / 2.0);
+    assert!(checkbox.x + checkbox.width < cancel.x);
+    assert!(cancel.x + cancel.width < ok.x);
+    assert!(
+        (checkbox.center_y() - cancel.center_y()).abs() < 1.0,
+        "checkbox should be centered on the buttons"
+    );
+
+    ui.point_at(Point::new(checkbox.x + 4.0, checkbox.center_y()));
+    let _ = ui.simulate(simulator::click());
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::DontAskAgain(true)]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn buttons_that_shrink_sit_against_the_right_padding() -> Result<(), Error> {
+    let dialog: Element<'_, Message> =
+        Dialog::new(true, center(text("Base")), text("Delete all your work?"))
+            .id("dialog")
+            .checkbox("Don't ask again", false, Message::DontAskAgain)
+            .push_button(
+                button(text("Cancel"))
+                    .padding(0)
+                    .on_press(Message::Answered(false)),
+            )
+            .push_button(
+                button(text("Delete"))
+                    .padding(0)
+                    .on_press(Message::Answered(true)),
+            )
+            .into();
+
+    let mut ui = simulator(dialog);
+
+    let dialog = ui.find(selector::id("dialog"))?.bounds();
+    let checkbox = ui.find("Don't ask again")?.bounds();
+    let cancel = ui.find("Cancel")?.bounds();
+    let delete = ui.find("Delete")?.bounds();
+
+    assert!(checkbox.x + checkbox.width < cancel.x);
+    assert!(cancel.x + cancel.width < delete.x);
+    assert!(
+        (delete.x + delete.width - (dialog.x + dialog.width - 24.0)).abs()
+            < 1.0,
+        "buttons should end at the right padding"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn answers_can_carry_the_checkbox() {
+    for is_checked in [false, true] {
+        for (key, answer) in [(Named::Enter, true), (Named::Escape, false)] {
+            let mut ui = simulator(
+                Confirm::new(
+                    true,
+                    center(text("Base")),
+                    "Delete",
+                    "Delete all your work?",
+                    move |answer| Message::AnsweredAsking(answer, is_checked),
+                )
+                .checkbox(
+                    "Don't ask again",
+                    is_checked,
+                    Message::DontAskAgain,
+                ),
+            );
+            let _ = ui.tap_key(key);
+
+            assert_eq!(
+                ui.into_messages().collect::<Vec<_>>(),
+                [Message::AnsweredAsking(answer, is_checked)]
+            );
+        }
+    }
+}
+
+#[test]
+fn checked_buttons_report_the_checkbox() -> Result<(), Error> {
+    let dialog = |is_checked| -> Element<'_, Message> {
+        Dialog::new(true, center(text("Base")), text("Delete all your work?"))
+            .checkbox("Don't ask again", is_checked, Message::DontAskAgain)
+            .push_checked_button("Cancel", |is_checked| {
+                Message::AnsweredAsking(false, is_checked)
+            })
+            .push_default_checked_button("Delete", |is_checked| {
+                Message::AnsweredAsking(true, is_checked)
+            })
+            .into()
+    };
+
+    for is_checked in [false, true] {
+        let mut ui = simulator(dialog(is_checked));
+        let _ = ui.click("Cancel")?;
+        let _ = ui.tap_key(Named::Enter);
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [
+                Message::AnsweredAsking(false, is_checked),
+                Message::AnsweredAsking(true, is_checked)
+            ]
+        );
+    }
+
+    Ok(())
+}
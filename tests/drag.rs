@@ -0,0 +1,70 @@
+#![allow(missing_docs)]
+mod common;
+#[path = "common/widgets.rs"]
+mod widgets;
+
+use common::Session;
+use iced::Element;
+use iced::{Event, Point, Rectangle, Vector, mouse};
+use iced_dialog::Dialog;
+use iced_test::{Error, selector};
+use iced_widget::{center, text};
+
+fn dialog(is_draggable: bool) -> Element<'static, ()> {
+    Dialog::new(true, center(text("Base")), text("Content"))
+        .id("dialog")
+        .title("Reference")
+        .padding_outer(20)
+        .draggable(is_draggable)
+        .into()
+}
+
+/// Drags the title area of the [`Dialog`] by the given distance and returns
+/// its bounds before and after.
+fn drag(
+    is_draggable: bool,
+    distance: Vector,
+) -> Result<(Rectangle, Rectangle), Error> {
+    let mut session = Session::new();
+    let mut ui = session.view(dialog(is_draggable));
+
+    let before = ui.find(selector::id("dialog"))?.bounds();
+    let grab = Point::new(before.center_x(), before.y + 5.0);
+
+    ui.move_to(grab);
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+        mouse::Button::Left,
+    ))]);
+    ui.move_to(grab + distance);
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonReleased(
+        mouse::Button::Left,
+    ))]);
+    drop(ui);
+
+    let after = session
+        .view(dialog(is_draggable))
+        .find(selector::id("dialog"))?
+        .bounds();
+
+    Ok((before, after))
+}
+
+#[test]
+fn dragging_moves_the_dialog_within_the_outer_padding() -> Result<(), Error> {
+    let (before, after) = drag(true, Vector::new(-1000.0, 50.0))?;
+
+    assert_eq!(after.x, 20.0);
+    assert_eq!(after.y, before.y + 50.0);
+    assert_eq!(after.size(), before.size());
+
+    Ok(())
+}
+
+#[test]
+fn dialogs_are_not_draggable_by_default() -> Result<(), Error> {
+    let (before, after) = drag(false, Vector::new(-100.0, 50.0))?;
+
+    assert_eq!(after, before);
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+#![allow(missing_docs)]
+use std::fmt;
+
+use iced::Element;
+use iced::keyboard::key::Named;
+use iced_dialog::dialog;
+use iced_test::simulator;
+use iced_widget::{center, text};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Message {
+    Toggled(bool),
+    Copied(String),
+    Closed,
+}
+
+#[derive(Debug)]
+struct Error {
+    message: &'static str,
+    source: Option<Box<Error>>,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+fn error(is_expanded: bool) -> Element<'static, Message> {
+    let error = Error {
+        message: "Export failed",
+        source: Some(Box::new(Error {
+            message: "Could not write frame 12",
+            source: Some(Box::new(Error {
+                message: "Disk is full",
+                source: None,
+            })),
+        })),
+    };
+
+    dialog::Error::new(
+        true,
+        center(text("Base")),
+        "Error",
+        &error,
+        Message::Toggled,
+        Message::Copied,
+        Message::Closed,
+    )
+    .expanded(is_expanded)
+    .into()
+}
+
+#[test]
+fn copy_includes_the_chain_of_sources() -> Result<(), iced_test::Error> {
+    let details = "Export failed\n\
+        \n\
+        Caused by:\n    \
+        0: Could not write frame 12\n    \
+        1: Disk is full";
+
+    for is_expanded in [false, true] {
+        let mut ui = simulator(error(is_expanded));
+        let _ = ui.click("Copy")?;
+
+        assert_eq!(
+            ui.into_messages().collect::<Vec<_>>(),
+            [Message::Copied(details.to_owned())]
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn enter_and_escape_close_with_the_details_focused() {
+    for key in [Named::Enter, Named::Escape] {
+        for is_expanded in [false, true] {
+            let mut ui = simulator(error(is_expanded));
+            let _ = ui.tap_key(key);
+
+            assert_eq!(
+                ui.into_messages().collect::<Vec<_>>(),
+                [Message::Closed],
+                "{key:?}, is_expanded: {is_expanded}"
+            );
+        }
+    }
+}
@@ -0,0 +1,70 @@
+#![allow(missing_docs)]
+use iced::Element;
+use iced::event;
+use iced::keyboard::key::Named;
+use iced_dialog::Dialog;
+use iced_test::simulator;
+use iced_widget::{center, text};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Escaped,
+    EscapedNested,
+}
+
+fn dialog(is_open: bool) -> Dialog<'static, Message> {
+    Dialog::new(is_open, center(text("Base")), text("Content"))
+}
+
+/// Presses Escape and returns whether the event was captured, with the
+/// produced messages.
+fn escape(
+    dialog: impl Into<Element<'static, Message>>,
+) -> (bool, Vec<Message>) {
+    let mut ui = simulator(dialog);
+    let status = ui.tap_key(Named::Escape);
+
+    (
+        status == event::Status::Captured,
+        ui.into_messages().collect(),
+    )
+}
+
+#[test]
+fn escape_produces_the_message() {
+    assert_eq!(
+        escape(dialog(true).on_escape(Message::Escaped)),
+        (true, vec![Message::Escaped])
+    );
+    assert_eq!(
+        escape(dialog(true).on_escape_with(|| Message::Escaped)),
+        (true, vec![Message::Escaped])
+    );
+}
+
+#[test]
+fn escape_is_ignored_without_a_message() {
+    assert_eq!(escape(dialog(true)), (false, vec![]));
+}
+
+#[test]
+fn closed_dialogs_ignore_escape() {
+    assert_eq!(
+        escape(dialog(false).on_escape(Message::Escaped)),
+        (false, vec![])
+    );
+}
+
+#[test]
+fn only_the_topmost_dialog_takes_escape() {
+    let nested = Dialog::new(true, text("Content"), text("Nested"))
+        .on_escape(Message::EscapedNested);
+
+    assert_eq!(
+        escape(
+            Dialog::new(true, center(text("Base")), nested)
+                .on_escape(Message::Escaped)
+        ),
+        (true, vec![Message::EscapedNested])
+    );
+}
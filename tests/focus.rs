@@ -0,0 +1,220 @@
+#![allow(missing_docs)]
+mod common;
+#[path = "common/widgets.rs"]
+mod widgets;
+
+use common::{Session, Ui};
+use iced::Element;
+use iced::keyboard::{self, Modifiers, key::Named};
+use iced::{Event, Point, Rectangle};
+use iced_dialog::Dialog;
+use iced_test::selector::Bounded;
+use iced_test::{Error, Selector, selector, simulator};
+use iced_widget::core::widget::Operation;
+use iced_widget::core::widget::operation::{self, Outcome, focusable};
+use iced_widget::{center, column, text, text_input};
+
+/// Returns a [`Dialog`] over a form with the given inputs.
+fn form(
+    is_open: bool,
+    inputs: &[(Option<&'static str>, &'static str)],
+) -> Element<'static, ()> {
+    let inputs = inputs.iter().map(|(id, value)| {
+        let input = text_input("", value).on_input(|_| ());
+
+        match id {
+            Some(id) => input.id(*id).into(),
+            None => input.into(),
+        }
+    });
+
+    Dialog::new(is_open, column(inputs), text("Content")).into()
+}
+
+/// Returns a [`Dialog`] with two inputs, over another input filling the
+/// window.
+fn fields(is_open: bool) -> Dialog<'static, ()> {
+    Dialog::new(
+        is_open,
+        center(text_input("", "base").id("base").on_input(|_| ())),
+        column![
+            text_input("", "first").id("first").on_input(|_| ()),
+            text_input("", "second").id("second").on_input(|_| ()),
+        ],
+    )
+}
+
+/// Returns the event of pressing the given key with the given modifiers.
+fn press_key(key: impl Into<keyboard::Key>, modifiers: Modifiers) -> Event {
+    let Event::Keyboard(keyboard::Event::KeyPressed {
+        key,
+        modified_key,
+        physical_key,
+        location,
+        repeat,
+        text,
+        ..
+    }) = simulator::press_key(key, None)
+    else {
+        unreachable!("a key press is a keyboard event");
+    };
+
+    Event::Keyboard(keyboard::Event::KeyPressed {
+        key,
+        modified_key,
+        physical_key,
+        location,
+        modifiers,
+        repeat,
+        text,
+    })
+}
+
+/// Clicks the center of the target of the given [`Selector`].
+fn click<S>(mut ui: Ui<'_, '_, ()>, selector: S) -> Result<(), Error>
+where
+    S: Selector + Send,
+    S::Output: Bounded + Clone + Send,
+{
+    let bounds = ui.find(selector)?.bounds();
+
+    ui.move_to(bounds.center());
+    let _ = ui.simulate(simulator::click());
+
+    Ok(())
+}
+
+/// Lets the open or closed [`Dialog`] handle an event, and returns the
+/// bounds of the focused widget, if any.
+fn focused(ui: &mut Ui<'_, '_, ()>) -> Option<Rectangle> {
+    ui.move_to(Point::ORIGIN);
+
+    ui.find(selector::is_focused())
+        .ok()
+        .map(|target| target.bounds())
+}
+
+#[test]
+fn focus_is_restored_by_id() -> Result<(), Error> {
+    let inputs = [(Some("first"), "a"), (Some("second"), "b")];
+    let mut session = Session::new();
+
+    click(session.view(form(false, &inputs)), selector::id("second"))?;
+
+    let mut ui = session.view(form(true, &inputs));
+    assert_eq!(focused(&mut ui), None, "base should lose the focus");
+    drop(ui);
+
+    // Another input is added before the focused one while the dialog is open
+    let inputs = [(Some("first"), "a"), (None, "new"), (Some("second"), "b")];
+
+    let mut ui = session.view(form(false, &inputs));
+    assert_eq!(
+        focused(&mut ui),
+        Some(ui.find(selector::id("second"))?.bounds())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn focus_is_restored_by_index_without_id() -> Result<(), Error> {
+    let inputs = [(None, "a"), (None, "b")];
+    let mut session = Session::new();
+
+    click(session.view(form(false, &inputs)), "b")?;
+
+    let mut ui = session.view(form(true, &inputs));
+    assert_eq!(focused(&mut ui), None, "base should lose the focus");
+    drop(ui);
+
+    let mut ui = session.view(form(false, &inputs));
+    assert_eq!(focused(&mut ui), Some(ui.find("b")?.bounds()));
+
+    Ok(())
+}
+
+#[test]
+fn opening_focuses_the_dialog() -> Result<(), Error> {
+    let mut session = Session::new();
+    click(session.view(fields(false)), selector::id("base"))?;
+
+    let mut ui = session.view(fields(true));
+    assert_eq!(
+        focused(&mut ui),
+        Some(ui.find(selector::id("first"))?.bounds())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn tab_cycles_within_the_dialog() -> Result<(), Error> {
+    let mut session = Session::new();
+    let mut ui = session.view(fields(true));
+    let _ = focused(&mut ui);
+
+    let first = ui.find(selector::id("first"))?.bounds();
+    let second = ui.find(selector::id("second"))?.bounds();
+
+    let _ = ui.simulate(simulator::tap_key(Named::Tab, None));
+    assert_eq!(focused(&mut ui), Some(second));
+
+    let _ = ui.simulate(simulator::tap_key(Named::Tab, None));
+    assert_eq!(focused(&mut ui), Some(first), "Tab should wrap around");
+
+    let _ = ui.simulate([press_key(Named::Tab, Modifiers::SHIFT)]);
+    assert_eq!(
+        focused(&mut ui),
+        Some(second),
+        "Shift+Tab should wrap around"
+    );
+
+    let _ = ui.simulate([press_key(Named::Tab, Modifiers::SHIFT)]);
+    assert_eq!(focused(&mut ui), Some(first));
+
+    Ok(())
+}
+
+#[test]
+fn changing_the_id_focuses_the_dialog_again() -> Result<(), Error> {
+    let mut session = Session::new();
+    let mut ui = session.view(fields(true).id("a"));
+    let _ = focused(&mut ui);
+    let _ = ui.simulate(simulator::tap_key(Named::Tab, None));
+
+    let second = ui.find(selector::id("second"))?.bounds();
+    assert_eq!(focused(&mut ui), Some(second));
+    drop(ui);
+
+    let mut ui = session.view(fields(true).id("a"));
+    assert_eq!(focused(&mut ui), Some(second));
+    drop(ui);
+
+    let mut ui = session.view(fields(true).id("b"));
+    assert_eq!(
+        focused(&mut ui),
+        Some(ui.find(selector::id("first"))?.bounds())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn base_is_hidden_from_focus_operations() {
+    let focusables = |is_open| {
+        let mut session = Session::new();
+        let mut ui = session.view(fields(is_open));
+        let mut count = focusable::count();
+
+        ui.operate(&mut operation::black_box(&mut count));
+
+        match count.finish() {
+            Outcome::Some(count) => count.total,
+            _ => 0,
+        }
+    };
+
+    assert_eq!(focusables(false), 1);
+    assert_eq!(focusables(true), 2);
+}
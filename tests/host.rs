@@ -0,0 +1,87 @@
+#![allow(missing_docs)]
+mod common;
+#[path = "common/widgets.rs"]
+mod widgets;
+
+use common::Session;
+use iced::Point;
+use iced::keyboard::key::Named;
+use iced_runtime::futures::futures::{StreamExt, executor};
+use iced_runtime::{Action, task};
+use iced_test::{Error, selector, simulator};
+use iced_widget::core::widget::Id;
+use iced_widget::core::widget::operation::focusable;
+use iced_widget::{text, text_input};
+
+#[test]
+fn confirm_produces_answer() {
+    let task = iced_dialog::confirm("Delete", "Are you sure?");
+    let mut actions = task::into_stream(task).expect("task should run");
+
+    let Some(Action::Widget(mut operation)) =
+        executor::block_on(actions.next())
+    else {
+        panic!("task should deliver the dialog to the host");
+    };
+
+    let mut session = Session::<()>::new();
+    session
+        .view(iced_dialog::host(text("Base")))
+        .operate(operation.as_mut());
+    drop(operation);
+
+    let mut ui = session.view(iced_dialog::host(text("Base")));
+    assert!(ui.find("Are you sure?").is_ok(), "dialog should be shown");
+    let _ = ui.simulate(simulator::tap_key(Named::Enter, None));
+    drop(ui);
+
+    let Some(Action::Output(answer)) = executor::block_on(actions.next())
+    else {
+        panic!("task should produce the answer");
+    };
+    assert!(answer);
+
+    let mut ui = session.view(iced_dialog::host(text("Base")));
+    assert!(ui.find("Are you sure?").is_err(), "dialog should be closed");
+}
+
+#[test]
+fn focus_returns_to_the_base() -> Result<(), Error> {
+    let host = || {
+        iced_dialog::host(text_input("", "Name").id("name").on_input(|_| ()))
+    };
+
+    let task = iced_dialog::confirm("Rename", "Are you sure?");
+    let mut actions = task::into_stream(task).expect("task should run");
+
+    let Some(Action::Widget(mut operation)) =
+        executor::block_on(actions.next())
+    else {
+        panic!("task should deliver the dialog to the host");
+    };
+
+    let mut session = Session::<()>::new();
+    let mut ui = session.view(host());
+    ui.operate(&mut focusable::focus(Id::new("name")));
+    ui.operate(operation.as_mut());
+    drop(operation);
+    drop(ui);
+
+    let mut ui = session.view(host());
+    ui.move_to(Point::ORIGIN);
+    assert!(
+        ui.find(selector::is_focused()).is_err(),
+        "base should lose the focus"
+    );
+    let _ = ui.simulate(simulator::tap_key(Named::Escape, None));
+    drop(ui);
+
+    let mut ui = session.view(host());
+    ui.move_to(Point::ORIGIN);
+    assert_eq!(
+        ui.find(selector::is_focused())?.bounds(),
+        ui.find(selector::id("name"))?.bounds()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+#![allow(missing_docs)]
+mod common;
+#[path = "common/widgets.rs"]
+mod widgets;
+
+use common::Session;
+use iced::{Element, Event, Length, Point, Rectangle, mouse};
+use iced_dialog::Dialog;
+use iced_dialog::dialog::Modality;
+use iced_test::{Error, selector, simulator};
+use iced_widget::core::widget::Id;
+use iced_widget::core::widget::operation::focusable;
+use iced_widget::{button, center, text, text_input};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Base,
+    Backdrop,
+}
+
+fn dialog(modality: Modality) -> Dialog<'static, Message> {
+    Dialog::new(
+        true,
+        button(text("Base"))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .on_press(Message::Base),
+        text("Content"),
+    )
+    .id("dialog")
+    .title("Find")
+    .on_press(Message::Backdrop)
+    .modality(modality)
+}
+
+/// Clicks the [`Dialog`] at the given position and returns the messages it
+/// produces.
+fn click(
+    dialog: impl Into<Element<'static, Message>>,
+    position: impl Fn(Rectangle) -> Point,
+) -> Result<Vec<Message>, Error> {
+    let mut ui = simulator(dialog);
+    let bounds = ui.find(selector::id("dialog"))?.bounds();
+
+    ui.point_at(position(bounds));
+    let _ = ui.simulate([
+        Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+    ]);
+
+    Ok(ui.into_messages().collect())
+}
+
+#[test]
+fn modal_dialogs_keep_the_base_from_input() -> Result<(), Error> {
+    let messages = click(dialog(Modality::Modal), |_| Point::new(5.0, 5.0))?;
+
+    assert_eq!(messages, [Message::Backdrop]);
+
+    Ok(())
+}
+
+#[test]
+fn modeless_dialogs_let_the_base_receive_input() -> Result<(), Error> {
+    for modality in [Modality::Modeless, Modality::ClickThrough] {
+        let messages = click(dialog(modality), |_| Point::new(5.0, 5.0))?;
+
+        assert_eq!(messages, [Message::Base], "{modality:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn modeless_dialogs_keep_input_over_them() -> Result<(), Error> {
+    let messages = click(dialog(Modality::Modeless), |bounds| bounds.center())?;
+
+    assert!(messages.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn modeless_dialogs_covering_the_window_only_cover_themselves()
+-> Result<(), Error> {
+    let dialog = || dialog(Modality::Modeless).cover_window(true);
+
+    let mut session = Session::new();
+    let mut ui = session.view(dialog());
+    let bounds = ui.find(selector::id("dialog"))?.bounds();
+
+    ui.move_to(Point::new(5.0, 5.0));
+    assert_eq!(ui.mouse_interaction(), mouse::Interaction::Pointer);
+
+    ui.move_to(bounds.center());
+    assert_eq!(ui.mouse_interaction(), mouse::Interaction::Idle);
+    drop(ui);
+
+    assert_eq!(click(dialog(), |_| Point::new(5.0, 5.0))?, [Message::Base]);
+    assert_eq!(click(dialog(), |bounds| bounds.center())?, []);
+
+    Ok(())
+}
+
+#[test]
+fn modeless_dialogs_keep_the_overlays_of_the_base() -> Result<(), Error> {
+    for modality in [Modality::Modal, Modality::Modeless] {
+        let base: Dialog<'_, Message> =
+            Dialog::new(true, center(text("Base")), text("Nested"))
+                .cover_window(true);
+        let mut ui = simulator(
+            Dialog::new(true, base, text("Content")).modality(modality),
+        );
+
+        assert_eq!(
+            ui.find("Nested").is_ok(),
+            modality == Modality::Modeless,
+            "{modality:?}"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn modeless_dialogs_leave_the_focus_to_the_base() -> Result<(), Error> {
+    let search = |is_open| -> Element<'static, Message> {
+        Dialog::new(
+            is_open,
+            center(text_input("Search", "").id("search")),
+            text_input("Replace", "").id("replace"),
+        )
+        .modality(Modality::Modeless)
+        .into()
+    };
+
+    let mut session = Session::new();
+    session
+        .view(search(false))
+        .operate(&mut focusable::focus(Id::new("search")));
+
+    for is_open in [true, false] {
+        let mut ui = session.view(search(is_open));
+        ui.move_to(Point::ORIGIN);
+
+        assert_eq!(
+            ui.find(selector::is_focused())?.bounds(),
+            ui.find(selector::id("search"))?.bounds(),
+            "is_open: {is_open}"
+        );
+    }
+
+    Ok(())
+}
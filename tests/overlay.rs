@@ -0,0 +1,59 @@
+#![allow(missing_docs)]
+use iced::Element;
+use iced::keyboard::key::Named;
+use iced::{Event, Point, mouse};
+use iced_dialog::dialog;
+use iced_test::simulator;
+use iced_widget::{container, text};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Outer,
+    OuterEscape,
+    Inner,
+    InnerEscape,
+}
+
+/// Presses the mouse far outside of the small base of the [`dialog`], then
+/// runs the given keys through it.
+fn update(dialog: Element<'_, Message>, keys: &[Named]) -> Vec<Message> {
+    let mut ui = simulator(container(dialog).width(200).height(100));
+
+    ui.point_at(Point::new(700.0, 500.0));
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+        mouse::Button::Left,
+    ))]);
+
+    for key in keys {
+        let _ = ui.tap_key(*key);
+    }
+
+    ui.into_messages().collect()
+}
+
+#[test]
+fn dialog_covers_window() {
+    let dialog = dialog(true, text("Base"), text("Content"))
+        .cover_window(true)
+        .on_press(Message::Outer);
+
+    assert_eq!(update(dialog.into(), &[]), [Message::Outer]);
+}
+
+#[test]
+fn only_topmost_dialog_receives_input() {
+    let inner = dialog(true, text("Settings"), text("Are you sure?"))
+        .cover_window(true)
+        .on_press(Message::Inner)
+        .on_escape(Message::InnerEscape);
+
+    let dialog = dialog(true, text("Base"), inner)
+        .cover_window(true)
+        .on_press(Message::Outer)
+        .on_escape(Message::OuterEscape);
+
+    assert_eq!(
+        update(dialog.into(), &[Named::Escape]),
+        [Message::Inner, Message::InnerEscape]
+    );
+}
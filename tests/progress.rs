@@ -0,0 +1,54 @@
+#![allow(missing_docs)]
+mod common;
+
+use common::Session;
+use iced::keyboard::key::Named;
+use iced::time::Instant;
+use iced::{Element, window};
+use iced_dialog::dialog::Progress;
+use iced_test::simulator;
+use iced_widget::{center, text};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Cancel,
+}
+
+fn progress(
+    value: Option<f32>,
+    is_dismissible: bool,
+) -> Element<'static, Message> {
+    Progress::new(true, center(text("Base")), "Exporting", value)
+        .status("Writing frames")
+        .on_cancel(Message::Cancel)
+        .dismissible(is_dismissible)
+        .into()
+}
+
+fn escape(progress: Element<'_, Message>) -> Vec<Message> {
+    let mut ui = simulator(progress);
+    let _ = ui.tap_key(Named::Escape);
+
+    ui.into_messages().collect()
+}
+
+/// Redraws the [`Progress`] and returns whether it asks to be redrawn on
+/// the next frame.
+fn is_animated(progress: Element<'_, Message>) -> bool {
+    let mut session = Session::new();
+    let mut ui = session.view(progress);
+
+    ui.redraw(Instant::now()) == window::RedrawRequest::NextFrame
+}
+
+#[test]
+fn dismissal_can_be_prevented() {
+    assert_eq!(escape(progress(Some(0.5), true)), [Message::Cancel]);
+    assert_eq!(escape(progress(Some(0.5), false)), []);
+}
+
+#[test]
+fn indeterminate_progress_is_animated() {
+    assert!(is_animated(progress(None, true)));
+    assert!(!is_animated(progress(Some(0.5), true)));
+}
@@ -0,0 +1,62 @@
+#![allow(missing_docs)]
+use iced::Element;
+use iced::keyboard::key::Named;
+use iced_dialog::dialog::Prompt;
+use iced_test::simulator;
+use iced_widget::{center, text};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Message {
+    Input(String),
+    Submit,
+    Cancel,
+}
+
+fn prompt(value: &str) -> Element<'_, Message> {
+    Prompt::new(
+        true,
+        center(text("Base")),
+        "Rename",
+        value,
+        Message::Input,
+        Message::Submit,
+        Message::Cancel,
+    )
+    .placeholder("File name")
+    .validate(|value| {
+        if value.is_empty() {
+            Err("The name cannot be empty".to_owned())
+        } else {
+            Ok(())
+        }
+    })
+    .into()
+}
+
+#[test]
+fn valid_value_is_submitted_once() {
+    let mut ui = simulator(prompt("notes.txt"));
+    let _ = ui.tap_key(Named::Enter);
+
+    assert_eq!(ui.into_messages().collect::<Vec<_>>(), [Message::Submit]);
+}
+
+#[test]
+fn invalid_value_is_not_submitted() {
+    let mut ui = simulator(prompt(""));
+    let _ = ui.tap_key(Named::Enter);
+
+    assert!(
+        ui.find("The name cannot be empty").is_ok(),
+        "error should be shown"
+    );
+    assert_eq!(ui.into_messages().collect::<Vec<_>>(), []);
+}
+
+#[test]
+fn escape_cancels_while_the_input_is_focused() {
+    let mut ui = simulator(prompt("notes.txt"));
+    let _ = ui.tap_key(Named::Escape);
+
+    assert_eq!(ui.into_messages().collect::<Vec<_>>(), [Message::Cancel]);
+}
@@ -0,0 +1,43 @@
+#![allow(missing_docs)]
+use iced::Element;
+use iced_dialog::dialog::queue::{Entry, Queue};
+use iced_test::simulator;
+use iced_widget::{center, text};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Dismiss,
+}
+
+/// Returns the content of the [`Entry`] shown by the [`Queue`], if any.
+fn shown(queue: &Queue<Message>) -> Option<&'static str> {
+    let view: Element<'_, Message> = queue.view(center(text("Base"))).into();
+    let mut ui = simulator(view);
+
+    ["Disk is full", "Discard changes?"]
+        .into_iter()
+        .find(|content| ui.find(*content).is_ok())
+}
+
+#[test]
+fn dismissing_advances_the_queue() {
+    let mut queue = Queue::new();
+    assert_eq!(shown(&queue), None);
+
+    queue.push(Entry::new("Disk is full").button("Ok", Message::Dismiss));
+    queue.push(
+        Entry::new("Discard changes?")
+            .title("Confirm")
+            .default_button("Discard", Message::Dismiss),
+    );
+    assert_eq!(queue.len(), 2);
+    assert_eq!(shown(&queue), Some("Disk is full"));
+
+    let _ = queue.dismiss();
+    assert_eq!(queue.len(), 1);
+    assert_eq!(shown(&queue), Some("Discard changes?"));
+
+    let _ = queue.dismiss();
+    assert!(queue.is_empty());
+    assert_eq!(shown(&queue), None);
+}
@@ -0,0 +1,108 @@
+#![allow(missing_docs)]
+mod common;
+#[path = "common/widgets.rs"]
+mod widgets;
+
+use common::Session;
+use iced::{Element, Event, Point, Rectangle, Size, Vector, mouse};
+use iced_dialog::Dialog;
+use iced_test::{Error, selector};
+use iced_widget::{center, text};
+
+fn dialog() -> Element<'static, ()> {
+    Dialog::new(true, center(text("Base")), text("Logs"))
+        .id("dialog")
+        .title("Logs")
+        .push_button(iced_dialog::button("Close", ()))
+        .min_width(300)
+        .resizable(true)
+        .into()
+}
+
+/// Drags the point of the [`Dialog`] returned by `grab` by the given
+/// distance, and returns the bounds of the [`Dialog`] before and after,
+/// with the mouse interaction shown when hovering the grabbed point.
+fn resize(
+    grab: impl Fn(Rectangle) -> Point,
+    distance: Vector,
+) -> Result<(Rectangle, Rectangle, mouse::Interaction), Error> {
+    let mut session = Session::new();
+    let mut ui = session.view(dialog());
+
+    let before = ui.find(selector::id("dialog"))?.bounds();
+    let grab = grab(before);
+
+    ui.move_to(grab);
+    let interaction = ui.mouse_interaction();
+
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonPressed(
+        mouse::Button::Left,
+    ))]);
+    ui.move_to(grab + distance);
+    let _ = ui.simulate([Event::Mouse(mouse::Event::ButtonReleased(
+        mouse::Button::Left,
+    ))]);
+    drop(ui);
+
+    let after = session
+        .view(dialog())
+        .find(selector::id("dialog"))?
+        .bounds();
+
+    Ok((before, after, interaction))
+}
+
+#[test]
+fn right_edge_grows_past_default_size() -> Result<(), Error> {
+    let (before, after, interaction) = resize(
+        |bounds| Point::new(bounds.x + bounds.width, bounds.center_y()),
+        Vector::new(100.0, 0.0),
+    )?;
+
+    assert_eq!(interaction, mouse::Interaction::ResizingHorizontally);
+    assert_eq!(after.x, before.x);
+    assert_eq!(after.width, before.width + 100.0);
+    assert_eq!(after.height, before.height);
+
+    Ok(())
+}
+
+#[test]
+fn left_edge_respects_minimum_width() -> Result<(), Error> {
+    let (before, after, _) = resize(
+        |bounds| Point::new(bounds.x, bounds.center_y()),
+        Vector::new(200.0, 0.0),
+    )?;
+
+    assert_eq!(after.width, 300.0);
+    assert_eq!(after.x + after.width, before.x + before.width);
+
+    Ok(())
+}
+
+#[test]
+fn corner_resizes_both_axes() -> Result<(), Error> {
+    let (before, after, interaction) = resize(
+        |bounds| Point::new(bounds.x + bounds.width, bounds.y + bounds.height),
+        Vector::new(50.0, 40.0),
+    )?;
+
+    assert_eq!(interaction, mouse::Interaction::ResizingDiagonallyDown);
+    assert_eq!(after.position(), before.position());
+    assert_eq!(after.size(), before.size() + Size::new(50.0, 40.0));
+
+    Ok(())
+}
+
+#[test]
+fn right_edge_stops_at_the_window() -> Result<(), Error> {
+    let (before, after, _) = resize(
+        |bounds| Point::new(bounds.x + bounds.width, bounds.center_y()),
+        Vector::new(2000.0, 0.0),
+    )?;
+
+    assert_eq!(after.x, before.x);
+    assert_eq!(after.x + after.width, common::SIZE.width);
+
+    Ok(())
+}
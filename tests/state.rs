@@ -0,0 +1,73 @@
+#![allow(missing_docs)]
+mod common;
+#[path = "common/widgets.rs"]
+mod widgets;
+
+use common::{Session, Ui};
+use iced::Element;
+use iced::{Event, Length, Point, Vector, mouse};
+use iced_dialog::dialog;
+use iced_test::Error;
+use iced_test::selector::{self, Target};
+use iced_widget::{scrollable, vertical_space};
+
+fn view(is_open: bool) -> Element<'static, ()> {
+    let base = scrollable(vertical_space().height(2000))
+        .id("base")
+        .width(Length::Fill)
+        .height(Length::Fill);
+
+    let content = scrollable(vertical_space().width(200).height(2000))
+        .id("content")
+        .height(200);
+
+    dialog(is_open, base, content).into()
+}
+
+/// Scrolls down whatever is under the center of the window.
+fn scroll(ui: &mut Ui<'_, '_, ()>) {
+    ui.move_to(Point::new(512.0, 384.0));
+    let _ = ui.simulate([Event::Mouse(mouse::Event::WheelScrolled {
+        delta: mouse::ScrollDelta::Pixels { x: 0.0, y: -100.0 },
+    })]);
+}
+
+/// Returns the offset of the scrollable with the given id.
+fn offset(ui: &mut Ui<'_, '_, ()>, id: &'static str) -> Result<Vector, Error> {
+    match ui.find(selector::id(id))? {
+        Target::Scrollable { translation, .. } => Ok(translation),
+        target => panic!("{id} should be a scrollable, not {target:?}"),
+    }
+}
+
+#[test]
+fn base_state_survives_toggling() -> Result<(), Error> {
+    let mut session = Session::new();
+
+    let mut ui = session.view(view(false));
+    scroll(&mut ui);
+    let scrolled = offset(&mut ui, "base")?;
+    assert_ne!(scrolled, Vector::ZERO, "base should be scrolled");
+    drop(ui);
+
+    let _ = offset(&mut session.view(view(true)), "content")?;
+    assert_eq!(offset(&mut session.view(view(false)), "base")?, scrolled);
+
+    Ok(())
+}
+
+#[test]
+fn content_state_survives_toggling() -> Result<(), Error> {
+    let mut session = Session::new();
+
+    let mut ui = session.view(view(true));
+    scroll(&mut ui);
+    let scrolled = offset(&mut ui, "content")?;
+    assert_ne!(scrolled, Vector::ZERO, "content should be scrolled");
+    drop(ui);
+
+    let _ = offset(&mut session.view(view(false)), "base")?;
+    assert_eq!(offset(&mut session.view(view(true)), "content")?, scrolled);
+
+    Ok(())
+}
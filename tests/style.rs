@@ -0,0 +1,327 @@
+#![allow(missing_docs)]
+mod common;
+#[path = "common/screenshot.rs"]
+mod screenshot;
+#[path = "common/widgets.rs"]
+mod widgets;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use common::Session;
+use iced::time::{Duration, Instant};
+use iced::{
+    Border, Color, Element, Event, Point, Rectangle, Shadow, Theme, Vector,
+    mouse,
+};
+use iced_dialog::Dialog;
+use iced_dialog::dialog::{self, Catalog, Status, Style, Transition};
+use iced_test::{Error, selector};
+use iced_widget::{button, center, container, text};
+use screenshot::Screenshot;
+
+#[test]
+fn the_default_style_describes_the_whole_dialog() {
+    for theme in [Theme::Light, Theme::Dark] {
+        let style = Catalog::style(
+            &theme,
+            &<Theme as Catalog>::default(),
+            Status::Active,
+        );
+
+        assert_eq!(style, dialog::default(&theme, Status::Active));
+        assert_eq!(style.background, Some(theme.palette().background.into()));
+    }
+}
+
+#[test]
+fn validation_errors_have_their_own_class() {
+    for theme in [Theme::Light, Theme::Dark] {
+        let style = text::Catalog::style(
+            &theme,
+            &<Theme as Catalog>::default_validation(),
+        );
+
+        assert_eq!(style.color, Some(theme.palette().danger));
+    }
+}
+
+#[test]
+fn the_default_button_stands_out() {
+    for theme in [Theme::Light, Theme::Dark] {
+        let style = |class| {
+            button::Catalog::style(&theme, &class, button::Status::Active)
+        };
+
+        assert_ne!(
+            style(<Theme as Catalog>::default_accent_button()),
+            style(<Theme as Catalog>::default_button())
+        );
+    }
+}
+
+#[test]
+fn backdrop_styles_leave_everything_else_to_the_classes() {
+    let style = Style::backdrop(Color::BLACK);
+
+    assert_eq!(style.backdrop_color, Color::BLACK);
+    assert_eq!(style.background, None);
+    assert_eq!(style.border, Border::default());
+    assert_eq!(style.shadow, Shadow::default());
+    assert_eq!(style.title_color, None);
+    assert_eq!(style.text_color, None);
+    assert_eq!(style.footer_background, None);
+}
+
+#[test]
+fn the_default_style_darkens_the_backdrop() {
+    for theme in [Theme::Light, Theme::Dark] {
+        let style = dialog::default(&theme, Status::Active);
+
+        assert_eq!(style.backdrop_color, Color::from_rgba(0.0, 0.0, 0.0, 0.3));
+        assert_eq!(
+            style,
+            Style {
+                background: style.background,
+                ..Style::backdrop(style.backdrop_color)
+            }
+        );
+    }
+}
+
+#[test]
+fn the_default_style_dims_inactive_dialogs() {
+    for theme in [Theme::Light, Theme::Dark] {
+        let active = dialog::default(&theme, Status::Active);
+        let inactive = dialog::default(&theme, Status::Inactive);
+
+        assert_eq!(inactive.backdrop_color, active.backdrop_color);
+        assert_ne!(inactive.background, active.background);
+    }
+}
+
+/// A [`Style`] with a distinct color for each of its parts.
+fn surface(_theme: &Theme, _status: Status) -> Style {
+    Style {
+        background: Some(Color::WHITE.into()),
+        border: Border {
+            color: Color::from_rgb8(255, 0, 0),
+            width: 4.0,
+            ..Border::default()
+        },
+        shadow: Shadow {
+            color: Color::from_rgb8(0, 0, 255),
+            offset: Vector::new(0.0, 16.0),
+            blur_radius: 0.0,
+        },
+        footer_background: Some(Color::from_rgb8(0, 255, 0).into()),
+        ..Style::backdrop(Color::TRANSPARENT)
+    }
+}
+
+/// Draws the given [`Dialog`] and returns its pixels and bounds.
+fn draw(dialog: Dialog<'_, ()>) -> Result<(Screenshot, Rectangle), Error> {
+    let mut session = Session::new();
+    let mut ui = session.view(
+        dialog
+            .id("dialog")
+            .title("Title")
+            .width(400)
+            .height(300)
+            .push_button(iced_dialog::button("Ok", ())),
+    );
+
+    let _ = ui.redraw(Instant::now());
+    let bounds = ui.find(selector::id("dialog"))?.bounds();
+
+    Ok((ui.screenshot(&Theme::Light), bounds))
+}
+
+#[test]
+fn the_style_draws_the_border_shadow_and_footer() -> Result<(), Error> {
+    let (screenshot, bounds) = draw(
+        Dialog::new(true, center(text("Base")), text("Content")).style(surface),
+    )?;
+
+    let border = Point::new(bounds.x + 1.0, bounds.center_y());
+    let shadow = Point::new(bounds.center_x(), bounds.y + bounds.height + 8.0);
+    let body = Point::new(bounds.x + 8.0, bounds.y + 8.0);
+    let footer = Point::new(bounds.x + 8.0, bounds.y + bounds.height - 8.0);
+
+    assert_eq!(screenshot.pixel(border), Color::from_rgb8(255, 0, 0));
+    assert_eq!(screenshot.pixel(shadow), Color::from_rgb8(0, 0, 255));
+    assert_eq!(screenshot.pixel(body), Color::WHITE);
+    assert_eq!(screenshot.pixel(footer), Color::from_rgb8(0, 255, 0));
+
+    Ok(())
+}
+
+#[test]
+fn the_container_class_overrides_the_style() -> Result<(), Error> {
+    let black = Color::from_rgb8(0, 0, 0);
+    let (screenshot, bounds) = draw(
+        Dialog::new(true, center(text("Base")), text("Content"))
+            .style(surface)
+            .container_style(move |_theme| container::Style {
+                background: Some(black.into()),
+                border: Border {
+                    color: black,
+                    width: 4.0,
+                    ..Border::default()
+                },
+                ..container::Style::default()
+            }),
+    )?;
+
+    let border = Point::new(bounds.x + 1.0, bounds.center_y());
+    let body = Point::new(bounds.x + 8.0, bounds.y + 8.0);
+    let footer = Point::new(bounds.x + 8.0, bounds.y + bounds.height - 8.0);
+
+    assert_eq!(screenshot.pixel(border), black);
+    assert_eq!(screenshot.pixel(body), black);
+    assert_eq!(screenshot.pixel(footer), black);
+
+    Ok(())
+}
+
+/// Returns a [`Dialog`] recording the [`Status`] it is styled with, with
+/// another [`Dialog`] in its content.
+fn stacked(
+    is_nested_open: bool,
+    status: &Rc<Cell<Option<Status>>>,
+) -> Element<'static, ()> {
+    let status = status.clone();
+    let nested = Dialog::new(is_nested_open, text("Content"), text("Nested"));
+
+    Dialog::new(true, center(text("Base")), nested)
+        .id("outer")
+        .title("Outer")
+        .draggable(true)
+        .style(move |theme, current| {
+            status.set(Some(current));
+            dialog::default(theme, current)
+        })
+        .into()
+}
+
+/// Redraws the [`Dialog`], sends it the given mouse events, draws it and
+/// returns the [`Status`] it was styled with.
+fn status_after(
+    dialog: Element<'_, ()>,
+    status: &Cell<Option<Status>>,
+    events: impl Fn(Rectangle) -> Vec<(mouse::Event, Point)>,
+) -> Result<Option<Status>, Error> {
+    let mut session = Session::new();
+    let mut ui = session.view(dialog);
+
+    let _ = ui.redraw(Instant::now());
+    let bounds = ui.find(selector::id("outer"))?.bounds();
+
+    for (event, position) in events(bounds) {
+        ui.move_to(position);
+        let _ = ui.simulate([Event::Mouse(event)]);
+    }
+
+    let _ = ui.screenshot(&Theme::Light);
+
+    Ok(status.get())
+}
+
+#[test]
+fn open_dialogs_are_active() -> Result<(), Error> {
+    let status = Rc::new(Cell::new(None));
+
+    assert_eq!(
+        status_after(stacked(false, &status), &status, |_| Vec::new())?,
+        Some(Status::Active)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn dialogs_underneath_another_one_are_inactive() -> Result<(), Error> {
+    let status = Rc::new(Cell::new(None));
+
+    assert_eq!(
+        status_after(stacked(true, &status), &status, |_| Vec::new())?,
+        Some(Status::Inactive)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn dialogs_being_moved_are_dragging() -> Result<(), Error> {
+    let status = Rc::new(Cell::new(None));
+
+    let status = status_after(stacked(false, &status), &status, |bounds| {
+        let grab = Point::new(bounds.center_x(), bounds.y + 5.0);
+
+        vec![(mouse::Event::ButtonPressed(mouse::Button::Left), grab)]
+    })?;
+
+    assert_eq!(status, Some(Status::Dragging));
+
+    Ok(())
+}
+
+/// Returns a [`Dialog`] with a [`Transition`], recording the [`Status`] it
+/// is styled with.
+fn animated(
+    is_open: bool,
+    status: &Rc<Cell<Option<Status>>>,
+) -> Element<'static, ()> {
+    let status = status.clone();
+
+    Dialog::new(is_open, center(text("Base")), text("Content"))
+        .transition(Transition::Scale)
+        .duration(Duration::from_millis(200))
+        .style(move |theme, current| {
+            status.set(Some(current));
+            dialog::default(theme, current)
+        })
+        .into()
+}
+
+/// Redraws the [`Dialog`] at the given time, draws it and returns the
+/// [`Status`] it was styled with.
+fn status_at(
+    session: &mut Session<()>,
+    dialog: Element<'_, ()>,
+    status: &Cell<Option<Status>>,
+    now: Instant,
+) -> Option<Status> {
+    let mut ui = session.view(dialog);
+
+    let _ = ui.redraw(now);
+    let _ = ui.screenshot(&Theme::Light);
+
+    status.get()
+}
+
+#[test]
+fn dialogs_playing_their_transition_are_opening_or_closing() {
+    let status = Rc::new(Cell::new(None));
+    let mut session = Session::new();
+    let start = Instant::now();
+    let at = |millis| start + Duration::from_millis(millis);
+
+    let _ = status_at(&mut session, animated(true, &status), &status, start);
+
+    assert_eq!(
+        status_at(&mut session, animated(true, &status), &status, at(100)),
+        Some(Status::Opening)
+    );
+    assert_eq!(
+        status_at(&mut session, animated(true, &status), &status, at(400)),
+        Some(Status::Active)
+    );
+
+    let _ = status_at(&mut session, animated(false, &status), &status, at(400));
+
+    assert_eq!(
+        status_at(&mut session, animated(false, &status), &status, at(500)),
+        Some(Status::Closing)
+    );
+}
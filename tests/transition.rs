@@ -0,0 +1,59 @@
+#![allow(missing_docs)]
+mod common;
+#[path = "common/screenshot.rs"]
+mod screenshot;
+
+use common::Session;
+use iced::time::{Duration, Instant};
+use iced::{Color, Element, Point, Theme};
+use iced_dialog::Dialog;
+use iced_dialog::dialog::Transition;
+use iced_widget::{center, text};
+
+fn view(is_open: bool, reduced_motion: bool) -> Element<'static, ()> {
+    Dialog::new(is_open, center(text("Base")), text("Content"))
+        .transition(Transition::Scale)
+        .duration(Duration::from_millis(200))
+        .reduced_motion(reduced_motion)
+        .into()
+}
+
+/// Redraws the [`Dialog`] at the given time and returns whether it is
+/// still shown, judging by its backdrop.
+fn is_shown(
+    session: &mut Session<()>,
+    dialog: Element<'_, ()>,
+    now: Instant,
+) -> bool {
+    let mut ui = session.view(dialog);
+    let _ = ui.redraw(now);
+
+    ui.screenshot(&Theme::Light)
+        .pixel(Point::new(1000.0, 700.0))
+        != Color::WHITE
+}
+
+#[test]
+fn closing_transition_keeps_dialog_visible() {
+    let mut session = Session::new();
+    let start = Instant::now();
+
+    let _ = is_shown(&mut session, view(true, false), start);
+    let opened = start + Duration::from_millis(200);
+    assert!(is_shown(&mut session, view(true, false), opened));
+
+    let closed = opened + Duration::from_millis(200);
+    assert!(is_shown(&mut session, view(false, false), closed));
+
+    let finished = closed + Duration::from_millis(400);
+    assert!(!is_shown(&mut session, view(false, false), finished));
+}
+
+#[test]
+fn reduced_motion_closes_instantly() {
+    let mut session = Session::new();
+    let start = Instant::now();
+
+    assert!(is_shown(&mut session, view(true, true), start));
+    assert!(!is_shown(&mut session, view(false, true), start));
+}
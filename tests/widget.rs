@@ -0,0 +1,105 @@
+#![allow(missing_docs)]
+use iced::Element;
+use iced::keyboard::key::Named;
+use iced::{Length, Point};
+use iced_dialog::Dialog;
+use iced_test::{Error, selector, simulator};
+use iced_widget::{button, text};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Message {
+    Base,
+    Saved,
+}
+
+fn dialog(is_open: bool) -> Element<'static, Message> {
+    Dialog::new(
+        is_open,
+        button(text("Base"))
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .on_press(Message::Base),
+        text("Content"),
+    )
+    .id("dialog")
+    .title("Save")
+    .push_default_button("Save work", Message::Saved)
+    .into()
+}
+
+#[test]
+fn closed_dialogs_leave_the_base_alone() -> Result<(), Error> {
+    let mut ui = simulator(dialog(false));
+
+    assert!(ui.find("Content").is_err(), "dialog should be closed");
+    let _ = ui.click("Base")?;
+
+    assert_eq!(ui.into_messages().collect::<Vec<_>>(), [Message::Base]);
+
+    Ok(())
+}
+
+#[test]
+fn open_dialogs_show_their_title_content_and_buttons() -> Result<(), Error> {
+    let mut ui = simulator(dialog(true));
+
+    let dialog = ui.find(selector::id("dialog"))?.bounds();
+
+    for text in ["Save", "Content", "Save work"] {
+        let bounds = ui.find(text)?.bounds();
+
+        assert!(dialog.contains(bounds.center()), "{text} should be shown");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn open_dialogs_are_centered_in_the_base() -> Result<(), Error> {
+    let mut ui = simulator(dialog(true));
+
+    let center = ui.find(selector::id("dialog"))?.bounds().center();
+
+    assert!(center.distance(Point::new(512.0, 384.0)) < 1.0);
+
+    Ok(())
+}
+
+#[test]
+fn open_dialogs_handle_their_own_events() -> Result<(), Error> {
+    let mut ui = simulator(dialog(true));
+
+    let _ = ui.click("Save work")?;
+    let _ = ui.tap_key(Named::Enter);
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Saved, Message::Saved]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn enter_presses_the_buttons_marked_as_default() -> Result<(), Error> {
+    let dialog: Element<'_, Message> =
+        Dialog::new(true, text("Base"), text("Content"))
+            .push_button(button(text("Cancel")).on_press(Message::Base))
+            .push_default(
+                button(text("Save work")).on_press(Message::Saved),
+                Message::Saved,
+            )
+            .into();
+
+    let mut ui = simulator(dialog);
+
+    let _ = ui.click("Save work")?;
+    let _ = ui.tap_key(Named::Enter);
+
+    assert_eq!(
+        ui.into_messages().collect::<Vec<_>>(),
+        [Message::Saved, Message::Saved]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+#![allow(missing_docs)]
+mod common;
+#[path = "common/widgets.rs"]
+mod widgets;
+
+use common::Session;
+use iced::Element;
+use iced::Point;
+use iced::keyboard::key::Named;
+use iced_dialog::dialog::Wizard;
+use iced_dialog::dialog::wizard::Page;
+use iced_test::{Error, selector, simulator};
+use iced_widget::{center, text, text_input};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Message {
+    Navigated(usize),
+    Edited(String),
+    Finished,
+    Cancelled,
+}
+
+fn wizard(current: usize, can_proceed: bool) -> Element<'static, Message> {
+    Wizard::new(
+        true,
+        center(text("Base")),
+        "Export",
+        current,
+        Message::Navigated,
+        Message::Finished,
+        Message::Cancelled,
+    )
+    .push(Page::new(
+        "Name",
+        text_input("File name", "")
+            .id("name")
+            .on_input(Message::Edited),
+    ))
+    .push(Page::new("Format", text("Pick a format")).can_proceed(can_proceed))
+    .push(Page::new("Summary", text("Ready to export")))
+    .into()
+}
+
+#[test]
+fn enter_goes_forward_when_the_page_can_proceed() {
+    let press = |current, can_proceed| {
+        let mut ui = simulator(wizard(current, can_proceed));
+        let _ = ui.tap_key(Named::Enter);
+
+        ui.into_messages().collect::<Vec<_>>()
+    };
+
+    assert_eq!(press(1, true), [Message::Navigated(2)]);
+    assert_eq!(press(1, false), []);
+    assert_eq!(press(2, true), [Message::Finished]);
+}
+
+#[test]
+fn the_step_is_shown_in_the_title() -> Result<(), Error> {
+    let mut ui = simulator(wizard(1, true));
+
+    let title = ui.find("Export \u{2014} Step 2 of 3: Format")?;
+    let content = ui.find("Pick a format")?;
+
+    assert!(title.bounds().y + title.bounds().height <= content.bounds().y);
+    assert!(ui.find("Step 2 of 3: Format").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn the_title_can_show_the_step_differently() {
+    let wizard: Element<'_, Message> = Wizard::new(
+        true,
+        center(text("Base")),
+        "Export",
+        1,
+        Message::Navigated,
+        Message::Finished,
+        Message::Cancelled,
+    )
+    .title_with(|step| {
+        format!("{} ({}/{})", step.title, step.number, step.total)
+    })
+    .push(Page::new("Name", text("Pick a name")))
+    .push(Page::new("Format", text("Pick a format")))
+    .into();
+
+    let mut ui = simulator(wizard);
+
+    assert!(ui.find("Format (2/2)").is_ok());
+    assert!(ui.find("Export \u{2014} Step 2 of 2: Format").is_err());
+}
+
+#[test]
+fn wizards_without_pages_cannot_be_finished() -> Result<(), Error> {
+    let wizard: Element<'_, Message> = Wizard::new(
+        true,
+        center(text("Base")),
+        "Export",
+        0,
+        Message::Navigated,
+        Message::Finished,
+        Message::Cancelled,
+    )
+    .into();
+
+    let mut ui = simulator(wizard);
+
+    let _ = ui.find("Export")?;
+    let _ = ui.click("Finish")?;
+    let _ = ui.tap_key(Named::Enter);
+
+    assert_eq!(ui.into_messages().collect::<Vec<_>>(), []);
+
+    Ok(())
+}
+
+#[test]
+fn pages_keep_their_state() -> Result<(), Error> {
+    let mut session = Session::new();
+
+    // Opening the wizard focuses the input of the first page
+    let mut ui = session.view(wizard(0, true));
+    ui.move_to(Point::ORIGIN);
+    assert_eq!(
+        ui.find(selector::is_focused())?.bounds(),
+        ui.find(selector::id("name"))?.bounds()
+    );
+    drop(ui);
+
+    let mut ui = session.view(wizard(1, true));
+    ui.move_to(Point::ORIGIN);
+    assert!(ui.find(selector::is_focused()).is_err());
+    drop(ui);
+
+    let mut ui = session.view(wizard(0, true));
+    ui.move_to(Point::ORIGIN);
+    assert_eq!(
+        ui.find(selector::is_focused())?.bounds(),
+        ui.find(selector::id("name"))?.bounds()
+    );
+
+    Ok(())
+}